@@ -1,27 +1,3 @@
 #!/bin/sh
 
-cargo run --release --bin 01
-cargo run --release --bin 02
-cargo run --release --bin 03
-cargo run --release --bin 04
-cargo run --release --bin 05
-cargo run --release --bin 06
-cargo run --release --bin 07
-cargo run --release --bin 08
-cargo run --release --bin 09
-cargo run --release --bin 10
-cargo run --release --bin 11
-cargo run --release --bin 12
-cargo run --release --bin 13
-cargo run --release --bin 14
-cargo run --release --bin 15
-cargo run --release --bin 16
-cargo run --release --bin 17
-cargo run --release --bin 18
-cargo run --release --bin 19
-cargo run --release --bin 20
-cargo run --release --bin 21
-cargo run --release --bin 22
-cargo run --release --bin 23
-cargo run --release --bin 24
-cargo run --release --bin 25
+cargo run --release --bin aoc -- all
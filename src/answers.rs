@@ -0,0 +1,184 @@
+use crate::error::ParseError;
+use crate::json::Value;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+// The answer of a part, whatever its type.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    // For the integers that do not fit in an i64.
+    BigInt(i128),
+    Str(String),
+    // No answer was found (e.g. the input has no solution).
+    #[default]
+    Unsolved,
+    // An answer with an explanation, such as who won a game.
+    Detail(Box<Answer>, String),
+}
+
+impl Answer {
+    pub fn with_detail(self, detail: impl Into<String>) -> Answer {
+        Answer::Detail(Box::new(self.value().clone()), detail.into())
+    }
+
+    // The answer without its detail.
+    pub fn value(&self) -> &Answer {
+        match self {
+            Answer::Detail(answer, _) => answer,
+            answer => answer,
+        }
+    }
+
+    pub fn detail(&self) -> Option<&str> {
+        match self {
+            Answer::Detail(_, detail) => Some(detail),
+            _ => None,
+        }
+    }
+
+    pub fn is_solved(&self) -> bool {
+        *self.value() != Answer::Unsolved
+    }
+
+    // The value as JSON: the normalized string, or null if unsolved.
+    pub fn to_json(&self) -> Value {
+        match self.value() {
+            Answer::Unsolved => Value::Null,
+            answer => Value::Str(answer.to_string()),
+        }
+    }
+}
+
+// Displays the value, followed by the detail in parentheses if any. The
+// value alone is given by value().to_string(), as recorded in the answers.
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::BigInt(n) => write!(f, "{}", n),
+            Answer::Str(s) => write!(f, "{}", s),
+            Answer::Unsolved => write!(f, "unsolved"),
+            Answer::Detail(answer, detail) => write!(f, "{} ({})", answer, detail),
+        }
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(n: i128) -> Answer {
+        i64::try_from(n).map_or(Answer::BigInt(n), Answer::Int)
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Answer {
+                Answer::from(n as i128)
+            }
+        })*
+    };
+}
+
+answer_from_int!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::Str(s.to_string())
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(answer: Option<T>) -> Answer {
+        answer.map_or(Answer::Unsolved, Into::into)
+    }
+}
+
+// Recorded answers, indexed by day and part (1 or 2).
+//
+// They are saved in a simple text format, one line per answer: "DD.P ANSWER",
+// where the answer is the rest of the line (possibly empty). Lines starting
+// with '#' are comments.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers(pub BTreeMap<(u32, u32), String>);
+
+impl Answers {
+    pub fn create_from(text: &str) -> Result<Answers, ParseError> {
+        let mut answers = BTreeMap::new();
+        for line in text.lines().filter(|l| !l.starts_with('#') && !l.is_empty()) {
+            let (key, answer) = line.split_once(' ').unwrap_or((line, ""));
+            let (day, part) = key.split_once('.')
+                .ok_or_else(|| ParseError::new("expected 'DAY.PART ANSWER'", line).locate(text))?;
+            let day = day.parse::<u32>().ok().filter(|d| crate::days::DAYS.contains(d))
+                .ok_or_else(|| ParseError::new("invalid day", day).locate(text))?;
+            let part = part.parse::<u32>().ok().filter(|p| *p == 1 || *p == 2)
+                .ok_or_else(|| ParseError::new("invalid part", part).locate(text))?;
+            answers.insert((day, part), answer.to_string());
+        }
+        Ok(Answers(answers))
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn set(&mut self, day: u32, part: u32, answer: &str) {
+        self.0.insert((day, part), answer.to_string());
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = String::from("# DAY.PART ANSWER\n");
+        for ((day, part), answer) in &self.0 {
+            text += &format!("{:02}.{} {}\n", day, part, answer);
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answer_types() {
+        assert_eq!(Answer::from(42usize), Answer::Int(42));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInt(u64::MAX as i128));
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from("a,b"), Answer::Str("a,b".to_string()));
+        assert_eq!(Answer::from(None::<i64>), Answer::Unsolved);
+        assert_eq!(Answer::from(Some(-3)), Answer::Int(-3));
+        assert!(!Answer::from(None::<i64>).is_solved());
+
+        let a = Answer::from(306).with_detail("player 2 wins");
+        assert_eq!(a.to_string(), "306 (player 2 wins)");
+        assert_eq!((a.value(), a.detail()), (&Answer::Int(306), Some("player 2 wins")));
+        assert_eq!(a.with_detail("again").to_string(), "306 (again)");
+
+        assert_eq!(Answer::Int(7).with_detail("x").to_json(), Value::str("7"));
+        assert_eq!(Answer::BigInt(1 << 70).to_json(), Value::str("1180591620717411303424"));
+        assert_eq!(Answer::Unsolved.with_detail("no loop").to_json(), Value::Null);
+    }
+
+    #[test]
+    fn save_and_load() {
+        let mut answers = Answers::default();
+        answers.set(21, 2, "a,b c");
+        answers.set(3, 1, "189");
+        answers.set(25, 2, "");
+        let text = answers.to_text();
+        assert_eq!(text, "# DAY.PART ANSWER\n03.1 189\n21.2 a,b c\n25.2 \n");
+        assert_eq!(Answers::create_from(&text), Ok(answers.clone()));
+        assert_eq!(answers.get(21, 2), Some("a,b c"));
+        assert_eq!(answers.get(21, 1), None);
+
+        assert_eq!(Answers::create_from("03.3 1").unwrap_err().column, Some(4));
+        assert_eq!(Answers::create_from("# ok\n26.1 1").unwrap_err().line, Some(2));
+        assert!(Answers::create_from("03 1").is_err());
+    }
+}
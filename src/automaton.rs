@@ -0,0 +1,259 @@
+// Cellular automata, i.e. variants of the Game of Life: at each step, all
+// the cells are updated at once, each one becoming alive or dead according
+// to a birth/survival rule given whether it is alive and the number of
+// its live neighbours.
+//
+// The cells are stored either sparsely, as the set of the live cells of
+// an unbounded space (with any type of coordinates and any neighbourhood
+// function), or densely, as a fixed set of cells numbered 0..n with their
+// neighbours computed once.
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+// A birth/survival rule, as in the "B3/S23" notation of the Game of Life:
+// a dead cell is born if its number of live neighbours is one of 'birth',
+// and a live cell survives if it is one of 'survival'. The numbers of
+// neighbours are at most 127.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rule {
+    birth: u128,
+    survival: u128,
+}
+
+impl Rule {
+    pub const fn new(birth: &[usize], survival: &[usize]) -> Rule {
+        Rule{ birth: mask(birth), survival: mask(survival) }
+    }
+
+    pub fn next(&self, alive: bool, live_neighbours: usize) -> bool {
+        let counts = if alive { self.survival } else { self.birth };
+        live_neighbours < 128 && counts >> live_neighbours & 1 == 1
+    }
+}
+
+const fn mask(counts: &[usize]) -> u128 {
+    let mut mask = 0;
+    let mut i = 0;
+    while i < counts.len() {
+        assert!(counts[i] < 128, "too many neighbours");
+        mask |= 1 << counts[i];
+        i += 1;
+    }
+    mask
+}
+
+// The rule of Conway's Game of Life.
+pub const LIFE: Rule = Rule::new(&[3], &[2, 3]);
+
+// Sparse storage: the set of the live cells. Only the live cells and their
+// neighbours are updated, so a rule giving birth to cells without live
+// neighbours is not supported.
+#[derive(Clone, Debug)]
+pub struct Sparse<C> {
+    live: HashSet<C>,
+    // the number of live neighbours, kept to reuse its memory
+    counts: HashMap<C, usize>,
+}
+
+impl<C: Copy + Eq + Hash> Sparse<C> {
+    pub fn new(live: impl IntoIterator<Item = C>) -> Sparse<C> {
+        Sparse{ live: live.into_iter().collect(), counts: HashMap::new() }
+    }
+
+    pub fn live(&self) -> &HashSet<C> {
+        &self.live
+    }
+
+    pub fn len(&self) -> usize {
+        self.live.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.live.is_empty()
+    }
+
+    // Advances by one step, 'neighbours' giving the neighbours of a cell.
+    pub fn step<I>(&mut self, rule: &Rule, neighbours: impl Fn(C) -> I) where
+        I: IntoIterator<Item = C>,
+    {
+        debug_assert!(!rule.next(false, 0), "sparse automata cannot give birth to isolated cells");
+
+        // Count the live neighbours of the cells which may change.
+        for &cell in &self.live {
+            self.counts.entry(cell).or_insert(0);
+            for n in neighbours(cell) {
+                *self.counts.entry(n).or_insert(0) += 1;
+            }
+        }
+        for (cell, n) in self.counts.drain() {
+            let alive = self.live.contains(&cell);
+            if rule.next(alive, n) != alive {
+                if alive { self.live.remove(&cell); } else { self.live.insert(cell); }
+            }
+        }
+    }
+}
+
+// Dense storage: the cells 0..n with their neighbours, computed once.
+// After each step, only the changed cells and their neighbours are updated.
+#[derive(Clone, Debug)]
+pub struct Dense {
+    alive: Vec<bool>,
+    neighbours: Vec<Vec<usize>>,
+    to_check: Vec<bool>,
+}
+
+impl Dense {
+    // Creates the automaton from the state of each cell, 'neighbours'
+    // giving the neighbours of a cell (in 0..n).
+    pub fn new<I>(alive: Vec<bool>, neighbours: impl Fn(usize) -> I) -> Dense where
+        I: IntoIterator<Item = usize>,
+    {
+        let neighbours: Vec<Vec<usize>> = (0..alive.len()).map(|i| neighbours(i).into_iter().collect()).collect();
+        assert!(neighbours.iter().flatten().all(|n| *n < alive.len()), "neighbour out of range");
+        let to_check = vec![true; alive.len()];
+        Dense{ alive, neighbours, to_check }
+    }
+
+    pub fn len(&self) -> usize {
+        self.alive.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.alive.is_empty()
+    }
+
+    pub fn is_alive(&self, cell: usize) -> bool {
+        self.alive[cell]
+    }
+
+    pub fn count_alive(&self) -> usize {
+        self.alive.iter().filter(|a| **a).count()
+    }
+
+    pub fn live_neighbours(&self, cell: usize) -> usize {
+        self.neighbours[cell].iter().filter(|n| self.alive[**n]).count()
+    }
+
+    // Advances by one step, returning whether any cell changed.
+    pub fn step(&mut self, rule: &Rule) -> bool {
+        let changed: Vec<usize> = (0..self.alive.len())
+            .filter(|&i| self.to_check[i] && rule.next(self.alive[i], self.live_neighbours(i)) != self.alive[i])
+            .collect();
+        self.to_check.fill(false);
+        for &i in &changed {
+            self.alive[i] = !self.alive[i];
+            // a cell may change again with the same live neighbours, when
+            // the rule does not let all the cells it gives birth to survive
+            self.to_check[i] = true;
+            for &n in &self.neighbours[i] {
+                self.to_check[n] = true;
+            }
+        }
+        !changed.is_empty()
+    }
+
+    // Advances until no cell changes (which may never happen), returning
+    // the number of steps which changed cells.
+    pub fn run_until_stable(&mut self, rule: &Rule) -> usize {
+        let mut steps = 0;
+        while self.step(rule) {
+            steps += 1;
+        }
+        steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn neighbours_2d((x, y): (i64, i64)) -> impl Iterator<Item = (i64, i64)> {
+        (-1..=1).flat_map(move |dy| (-1..=1).map(move |dx| (x + dx, y + dy)))
+            .filter(move |&n| n != (x, y))
+    }
+
+    #[test]
+    fn rules() {
+        assert!(LIFE.next(false, 3) && LIFE.next(true, 2) && LIFE.next(true, 3));
+        assert!(!LIFE.next(false, 2) && !LIFE.next(true, 4) && !LIFE.next(true, 1));
+        let rule = Rule::new(&[0], &[0, 1, 2, 3, 100]);
+        assert!(rule.next(false, 0) && rule.next(true, 100));
+        assert!(!rule.next(false, 1) && !rule.next(true, 4) && !rule.next(true, 1000));
+    }
+
+    #[test]
+    fn sparse() {
+        // a blinker oscillates, a block stays, a lone cell dies
+        let blinker = [(0, 1), (1, 1), (2, 1)];
+        let mut cells = Sparse::new(blinker);
+        cells.step(&LIFE, neighbours_2d);
+        assert_eq!(cells.live(), &HashSet::from([(1, 0), (1, 1), (1, 2)]));
+        cells.step(&LIFE, neighbours_2d);
+        assert_eq!(cells.live(), &HashSet::from(blinker));
+
+        let mut cells = Sparse::new([(0, 0), (0, 1), (1, 0), (1, 1), (10, 10)]);
+        cells.step(&LIFE, neighbours_2d);
+        assert_eq!(cells.len(), 4);
+        assert!(!cells.live().contains(&(10, 10)));
+        cells.step(&LIFE, |_| []);
+        assert!(cells.is_empty());
+    }
+
+    #[test]
+    fn dense() {
+        // a row of cells, each one lighting its neighbours, until all are lit
+        let n = 10;
+        let neighbours = |i: usize| [i.checked_sub(1), Some(i + 1).filter(|&j| j < n)].into_iter().flatten();
+        let mut alive = vec![false; n];
+        alive[3] = true;
+        let mut cells = Dense::new(alive, neighbours);
+        assert_eq!(cells.live_neighbours(2), 1);
+        let spread = Rule::new(&[1, 2], &[0, 1, 2]);
+        assert!(cells.step(&spread));
+        assert_eq!(cells.count_alive(), 3);
+        assert_eq!(cells.run_until_stable(&spread), 5);
+        assert_eq!(cells.count_alive(), n);
+        assert!(!cells.step(&spread));
+
+        // and the same on a dense 2D Life, compared with the sparse storage
+        let side = 12;
+        let index = |(x, y): (i64, i64)| (y * side + x) as usize;
+        let start = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]; // a glider
+        let mut alive = vec![false; (side * side) as usize];
+        start.iter().for_each(|&c| alive[index(c)] = true);
+        let mut dense = Dense::new(alive, |i| {
+            let c = (i as i64 % side, i as i64 / side);
+            neighbours_2d(c).filter(|&(x, y)| (0..side).contains(&x) && (0..side).contains(&y)).map(index)
+        });
+        let mut sparse = Sparse::new(start);
+        for _ in 0..20 {
+            dense.step(&LIFE);
+            sparse.step(&LIFE, neighbours_2d);
+            let live: HashSet<usize> = sparse.live().iter().map(|&c| index(c)).collect();
+            assert!((0..dense.len()).all(|i| dense.is_alive(i) == live.contains(&i)));
+        }
+        assert_eq!(dense.count_alive(), 5);
+    }
+
+    #[test]
+    fn dense_births_without_survival() {
+        // under B0/S an isolated cell blinks forever
+        let blink = Rule::new(&[0], &[]);
+        let mut cells = Dense::new(vec![false], |_| []);
+        for i in 0..10 {
+            assert!(cells.step(&blink));
+            assert_eq!(cells.is_alive(0), i % 2 == 0);
+        }
+
+        // under Seeds (B2/S) every cell dies right after its birth
+        let seeds = Rule::new(&[2], &[]);
+        let mut cells = Dense::new(vec![true, false, true], |i| [(i + 1) % 3, (i + 2) % 3]);
+        assert!(cells.step(&seeds));
+        assert_eq!(cells.count_alive(), 1);
+        assert!(cells.step(&seeds));
+        assert_eq!(cells.count_alive(), 0);
+        assert!(!cells.step(&seeds));
+    }
+}
@@ -0,0 +1,154 @@
+use crate::error::ParseError;
+use crate::params::Params;
+use crate::solution::AnySolution;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+// The phases of a solution that are timed separately.
+pub const PHASES: [&str; 3] = ["parse", "part1", "part2"];
+
+// Summary statistics of a set of timings, in nanoseconds.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "no samples");
+        let mut ns: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        ns.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let n = ns.len();
+        let median = if n % 2 == 1 { ns[n / 2] } else { (ns[n / 2 - 1] + ns[n / 2]) / 2.0 };
+        let mean = ns.iter().sum::<f64>() / n as f64;
+        let variance = ns.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n as f64;
+        Stats{ min: ns[0], median, mean, stddev: variance.sqrt() }
+    }
+}
+
+// The timings of one day, one entry per phase (in the order of PHASES).
+#[derive(Clone, Debug, PartialEq)]
+pub struct DayBench {
+    pub day: u32,
+    pub phases: [Stats; 3],
+}
+
+// Runs a solution 'warmup' times without measuring, then 'runs' times
+// measuring parsing and the two parts separately.
+pub fn bench_day(s: &dyn AnySolution, input: &str, params: &Params, warmup: usize, runs: usize) -> Result<DayBench, ParseError> {
+    let mut samples = [Vec::new(), Vec::new(), Vec::new()];
+    for run in 0..warmup + runs {
+        let now = Instant::now();
+        let parsed = s.parse_input(black_box(input), params)?;
+        let t_parse = now.elapsed();
+
+        let now = Instant::now();
+        black_box(s.run_part1(parsed.as_ref()));
+        let t_part1 = now.elapsed();
+
+        let now = Instant::now();
+        black_box(s.run_part2(parsed.as_ref()));
+        let t_part2 = now.elapsed();
+
+        if run >= warmup {
+            samples[0].push(t_parse);
+            samples[1].push(t_part1);
+            samples[2].push(t_part2);
+        }
+    }
+    Ok(DayBench{ day: s.day(), phases: samples.map(|s| Stats::from_samples(&s)) })
+}
+
+// Formats a number of nanoseconds with a readable unit.
+pub fn format_ns(ns: f64) -> String {
+    if ns < 1e3 {
+        format!("{:.0}ns", ns)
+    } else if ns < 1e6 {
+        format!("{:.3}µs", ns / 1e3)
+    } else if ns < 1e9 {
+        format!("{:.3}ms", ns / 1e6)
+    } else {
+        format!("{:.3}s", ns / 1e9)
+    }
+}
+
+// Saves the results in a simple text format, one line per day and phase:
+// "DAY PHASE MIN MEDIAN MEAN STDDEV", times in nanoseconds.
+pub fn to_text(results: &[DayBench]) -> String {
+    let mut text = String::from("# day phase min median mean stddev (ns)\n");
+    for r in results {
+        for (phase, st) in PHASES.iter().zip(r.phases.iter()) {
+            writeln!(text, "{:02} {} {:.0} {:.0} {:.0} {:.0}", r.day, phase, st.min, st.median, st.mean, st.stddev).unwrap();
+        }
+    }
+    text
+}
+
+// Results saved by a previous benchmark, indexed by day and phase.
+pub type Baseline = HashMap<(u32, String), Stats>;
+
+// Reads results saved with to_text().
+pub fn from_text(text: &str) -> Result<Baseline, ParseError> {
+    let mut results = HashMap::new();
+    for line in text.lines().filter(|l| !l.starts_with('#') && !l.trim().is_empty()) {
+        let fields: Vec<_> = line.split_whitespace().collect();
+        if fields.len() != 6 {
+            return Err(ParseError::new("expected 'DAY PHASE MIN MEDIAN MEAN STDDEV'", line).locate(text));
+        }
+        let number = |s: &str| s.parse::<f64>().map_err(|_| ParseError::new("invalid number", s).locate(text));
+        let day = fields[0].parse().map_err(|_| ParseError::new("invalid day", fields[0]).locate(text))?;
+        let stats = Stats{
+            min: number(fields[2])?,
+            median: number(fields[3])?,
+            mean: number(fields[4])?,
+            stddev: number(fields[5])?,
+        };
+        results.insert((day, fields[1].to_string()), stats);
+    }
+    Ok(results)
+}
+
+// Relative change of the median with respect to a baseline (0.1 = 10% slower).
+pub fn change(baseline: &Stats, current: &Stats) -> f64 {
+    (current.median - baseline.median) / baseline.median.max(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let samples: Vec<_> = [4, 1, 3, 2, 5].iter().map(|&n| Duration::from_nanos(n)).collect();
+        let st = Stats::from_samples(&samples);
+        assert_eq!((st.min, st.median, st.mean), (1.0, 3.0, 3.0));
+        assert!((st.stddev - 2f64.sqrt()).abs() < 1e-9);
+        assert_eq!(Stats::from_samples(&samples[..4]).median, 2.5);
+    }
+
+    #[test]
+    fn save_and_load() {
+        let st = Stats{ min: 100.0, median: 120.0, mean: 125.0, stddev: 8.0 };
+        let results = vec![DayBench{ day: 7, phases: [st, st, st] }];
+        let loaded = from_text(&to_text(&results)).unwrap();
+        assert_eq!(loaded.len(), 3);
+        assert_eq!(loaded[&(7, "part2".to_string())], st);
+        assert!((change(&st, &Stats{ median: 132.0, ..st }) - 0.1).abs() < 1e-9);
+
+        let e = from_text("01 parse 1 2 3\n").unwrap_err();
+        assert_eq!(e.line, Some(1));
+    }
+
+    #[test]
+    fn units() {
+        assert_eq!(format_ns(850.0), "850ns");
+        assert_eq!(format_ns(12_345.0), "12.345µs");
+        assert_eq!(format_ns(1_234_567.0), "1.235ms");
+        assert_eq!(format_ns(2_500_000_000.0), "2.500s");
+    }
+}
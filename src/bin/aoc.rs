@@ -0,0 +1,968 @@
+use advent_of_code_2020::answers::{Answer, Answers};
+use advent_of_code_2020::bench::{self, DayBench};
+use advent_of_code_2020::days;
+use advent_of_code_2020::fixtures;
+use advent_of_code_2020::fuzz::{self, Case};
+use advent_of_code_2020::generate;
+use advent_of_code_2020::input::Source;
+use advent_of_code_2020::json::Value;
+use advent_of_code_2020::memory::{self, AllocStats, CountingAllocator};
+use advent_of_code_2020::params::{self, Params};
+use advent_of_code_2020::pool;
+use advent_of_code_2020::rng::Rng;
+use advent_of_code_2020::solution::{AnySolution, ParsedInput};
+use advent_of_code_2020::trace;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+// Allocations are only counted with --alloc.
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const USAGE: &str = "\
+Usage: aoc [OPTIONS] [DAYS...]
+       aoc generate [OPTIONS] [DAYS...]
+       aoc fuzz [OPTIONS] [DAYS...]
+
+Runs the solutions for the given days and prints a summary table, or
+generates random inputs for them (see 'aoc generate --help'), or fuzzes
+their parsers (see 'aoc fuzz --help').
+DAYS can be single days (3), ranges (17-20) or 'all' (the default).
+
+Options:
+  --input PATH       Read the input of the (single) selected day from PATH,
+                     or from the standard input if PATH is '-'
+  --input-dir DIR    Read the inputs from DIR/NN.txt (default: input)
+  --param NAME=VALUE Override a parameter of the selected days (repeatable)
+  --params           List the parameters of the selected days
+  --jobs N           Run the days on N threads, with the two parts of each
+                     day in parallel (default: 1)
+  --trace SPEC       Print trace events of the solutions on stderr, e.g. 'debug'
+                     for all days or 'info,8=trace,14-16=debug' per day
+                     (default: the AOC_TRACE environment variable)
+  --alloc            Count the allocations and the peak heap usage of each
+                     day, for parsing and the two parts
+  --json             Print the results as JSON
+  --bench            Benchmark the days instead, timing parsing and the two
+                     parts separately over several runs
+  --runs N           Number of measured runs per day (default: 10)
+  --warmup N         Number of runs before measuring (default: 2)
+  --output PATH      Where to save the benchmark results (default: bench_output.txt)
+  --baseline PATH    Compare with the results saved in a previous benchmark
+  --threshold PCT    Slowdown of the median reported as a regression (default: 10)
+  --check            Compare the answers with the recorded ones, failing on mismatch
+  --record           Record the answers of the given days
+  --answers PATH     The file of the recorded answers (default: answers.txt)
+  -h, --help         Print this help";
+
+const GENERATE_USAGE: &str = "\
+Usage: aoc generate [OPTIONS] [DAYS...]
+
+Generates random valid inputs for the given days.
+DAYS can be single days (3), ranges (17-20) or 'all' (the default).
+
+Options:
+  --scale X          Size of the inputs relative to the real ones (default: 1)
+  --seed N           Seed of the random generator (default: 2020)
+  --output-dir DIR   Write the inputs to DIR/NN.txt, and the answers known by
+                     construction to DIR/answers.txt (by default, the input of
+                     the single selected day is written to the standard output)
+  -h, --help         Print this help";
+
+const FUZZ_USAGE: &str = "\
+Usage: aoc fuzz [OPTIONS] [DAYS...]
+
+Fuzzes the parsers of the given days with mutations of their examples,
+until interrupted, saving the inputs which make a parser panic.
+DAYS can be single days (3), ranges (17-20) or 'all' (the default).
+
+Options:
+  --seed N           Seed of the random generator (default: 2020)
+  --iterations N     Stop after N inputs (default: run forever)
+  --fixtures DIR     Read the examples from DIR/NN/NAME.txt (default: fixtures)
+  --crashers DIR     Save the crashers to DIR/NN/NAME.txt (default: fuzz)
+  -h, --help         Print this help";
+
+struct Options {
+    days: Vec<u32>,
+    input: Source,
+    params: Vec<(String, String)>,
+    list_params: bool,
+    jobs: usize,
+    trace: Option<String>,
+    alloc: bool,
+    json: bool,
+    bench: bool,
+    runs: usize,
+    warmup: usize,
+    output: String,
+    baseline: Option<String>,
+    threshold: f64,
+    check: bool,
+    record: bool,
+    answers: String,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options{
+        days: Vec::new(),
+        input: Source::default(),
+        params: Vec::new(),
+        list_params: false,
+        jobs: 1,
+        trace: None,
+        alloc: false,
+        json: false,
+        bench: false,
+        runs: 10,
+        warmup: 2,
+        output: String::from("bench_output.txt"),
+        baseline: None,
+        threshold: 10.0,
+        check: false,
+        record: false,
+        answers: String::from("answers.txt"),
+    };
+    let mut selectors = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("missing value for {}", arg));
+        match arg.as_str() {
+            "--input" => options.input = Source::from_arg(value()?),
+            "--input-dir" => options.input = Source::Dir(value()?.into()),
+            "--param" => options.params.push(params::parse_assignment(value()?)?),
+            "--params" => options.list_params = true,
+            "--jobs" => options.jobs = parse_value(arg, value()?)?,
+            "--trace" => options.trace = Some(value()?.clone()),
+            "--alloc" => options.alloc = true,
+            "--json" => options.json = true,
+            "--bench" => options.bench = true,
+            "--runs" => options.runs = parse_value(arg, value()?)?,
+            "--warmup" => options.warmup = parse_value(arg, value()?)?,
+            "--output" => options.output = value()?.clone(),
+            "--baseline" => options.baseline = Some(value()?.clone()),
+            "--threshold" => options.threshold = parse_value(arg, value()?)?,
+            "--check" => options.check = true,
+            "--record" => options.record = true,
+            "--answers" => options.answers = value()?.clone(),
+            _ if arg.starts_with('-') => return Err(format!("unknown option: {}", arg)),
+            _ => selectors.push(arg.clone()),
+        }
+    }
+    if [options.bench, options.check, options.record].iter().filter(|&&b| b).count() > 1 {
+        return Err(String::from("--bench, --check and --record cannot be used together"));
+    }
+    if options.jobs == 0 {
+        return Err(String::from("--jobs must be at least 1"));
+    }
+    if options.bench && options.jobs > 1 {
+        return Err(String::from("--jobs cannot be used with --bench, which runs the days one at a time"));
+    }
+    if options.bench && options.alloc {
+        return Err(String::from("--alloc cannot be used with --bench"));
+    }
+    if let Some(spec) = &options.trace {
+        trace::parse_spec(spec)?;
+    }
+    if options.runs == 0 {
+        return Err(String::from("--runs must be at least 1"));
+    }
+    options.days = parse_days(&selectors)?;
+    if !options.input.is_dir() && options.days.len() != 1 {
+        return Err(String::from("--input requires a single day"));
+    }
+    for (name, _) in &options.params {
+        if !options.days.iter().any(|&day| days::get(day).unwrap().params().iter().any(|p| p.name == name)) {
+            return Err(format!("no selected day has a parameter {}", name));
+        }
+    }
+    Ok(options)
+}
+
+fn parse_value<T: std::str::FromStr>(option: &str, value: &str) -> Result<T, String> {
+    value.parse().map_err(|_| format!("invalid value for {}: {}", option, value))
+}
+
+// Parses a list of day selectors such as "3", "17-20" or "all".
+fn parse_days(args: &[String]) -> Result<Vec<u32>, String> {
+    let mut selected = Vec::new();
+    for arg in args {
+        if arg == "all" {
+            selected.extend(days::DAYS);
+            continue;
+        }
+        let (first, last) = arg.split_once('-').unwrap_or((arg, arg));
+        let first = first.parse::<u32>().map_err(|_| format!("invalid day: {}", arg))?;
+        let last = last.parse::<u32>().map_err(|_| format!("invalid day: {}", arg))?;
+        if !days::DAYS.contains(&first) || !days::DAYS.contains(&last) || first > last {
+            return Err(format!("invalid day: {}", arg));
+        }
+        selected.extend(first..=last);
+    }
+    if args.is_empty() {
+        selected.extend(days::DAYS);
+    }
+    selected.sort_unstable();
+    selected.dedup();
+    Ok(selected)
+}
+
+struct GenerateOptions {
+    days: Vec<u32>,
+    scale: f64,
+    seed: u64,
+    output_dir: Option<PathBuf>,
+}
+
+fn parse_generate_args(args: &[String]) -> Result<GenerateOptions, String> {
+    let mut options = GenerateOptions{ days: Vec::new(), scale: 1.0, seed: 2020, output_dir: None };
+    let mut selectors = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("missing value for {}", arg));
+        match arg.as_str() {
+            "--scale" => options.scale = parse_value(arg, value()?)?,
+            "--seed" => options.seed = parse_value(arg, value()?)?,
+            "--output-dir" => options.output_dir = Some(value()?.into()),
+            _ if arg.starts_with('-') => return Err(format!("unknown option: {}", arg)),
+            _ => selectors.push(arg.clone()),
+        }
+    }
+    if !(options.scale > 0.0 && options.scale.is_finite()) {
+        return Err(String::from("--scale must be a positive number"));
+    }
+    options.days = parse_days(&selectors)?;
+    if options.output_dir.is_none() && options.days.len() != 1 {
+        return Err(String::from("writing to the standard output requires a single day"));
+    }
+    Ok(options)
+}
+
+// Generates the inputs, each day with its own generator seeded with the
+// same seed, so that an input does not depend on the other selected days.
+fn run_generate(options: &GenerateOptions) -> Result<(), String> {
+    let generate = |day| generate::generate(day, options.scale, &mut Rng::new(options.seed)).unwrap();
+    let Some(dir) = &options.output_dir else {
+        print!("{}", generate(options.days[0]).input);
+        return Ok(());
+    };
+
+    std::fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    let mut answers = Answers::default();
+    for &day in &options.days {
+        let generated = generate(day);
+        let path = Source::Dir(dir.clone()).path(day).unwrap();
+        std::fs::write(&path, &generated.input).map_err(|e| format!("{}: {}", path.display(), e))?;
+        for (part, answer) in [(1, &generated.part1), (2, &generated.part2)] {
+            if let Some(answer) = answer {
+                answers.set(day, part, answer);
+            }
+        }
+    }
+    let path = dir.join("answers.txt");
+    std::fs::write(&path, answers.to_text()).map_err(|e| format!("{}: {}", path.display(), e))?;
+    eprintln!("generated {} input(s) in {}", options.days.len(), dir.display());
+    Ok(())
+}
+
+struct FuzzOptions {
+    days: Vec<u32>,
+    seed: u64,
+    iterations: Option<u64>,
+    fixtures: PathBuf,
+    crashers: PathBuf,
+}
+
+fn parse_fuzz_args(args: &[String]) -> Result<FuzzOptions, String> {
+    let mut options = FuzzOptions{
+        days: Vec::new(),
+        seed: 2020,
+        iterations: None,
+        fixtures: fixtures::DEFAULT_DIR.into(),
+        crashers: fuzz::DEFAULT_DIR.into(),
+    };
+    let mut selectors = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("missing value for {}", arg));
+        match arg.as_str() {
+            "--seed" => options.seed = parse_value(arg, value()?)?,
+            "--iterations" => options.iterations = Some(parse_value(arg, value()?)?),
+            "--fixtures" => options.fixtures = value()?.into(),
+            "--crashers" => options.crashers = value()?.into(),
+            _ if arg.starts_with('-') => return Err(format!("unknown option: {}", arg)),
+            _ => selectors.push(arg.clone()),
+        }
+    }
+    options.days = parse_days(&selectors)?;
+    Ok(options)
+}
+
+// Fuzzes the parsers, saving the first crasher of each panic location and
+// printing the progress every few seconds. Returns the number of crashes.
+fn run_fuzz(options: &FuzzOptions) -> Result<usize, String> {
+    let seeds: Vec<Case> = fixtures::discover(&options.fixtures)?.iter()
+        .filter(|f| options.days.contains(&f.day))
+        .map(Case::from_fixture)
+        .collect();
+    if seeds.is_empty() {
+        return Err(format!("no examples of the selected days in {}", options.fixtures.display()));
+    }
+    eprintln!("fuzzing {} example(s) with seed {}", seeds.len(), options.seed);
+
+    fuzz::quiet_panics();
+    let start = Instant::now();
+    let mut last_report = start;
+    let mut locations = std::collections::HashSet::new();
+    let mut crashes = 0;
+    let mut error = None;
+    let iterations = fuzz::run(&seeds, options.seed, options.iterations, |i, crash| {
+        crashes += 1;
+        let location = crash.panic.location.as_ref().unwrap_or(&crash.panic.message);
+        if locations.insert((crash.case.day, location.clone())) {
+            match fuzz::save_crasher(&options.crashers, &crash.case) {
+                Ok(path) => eprintln!("day {}: {} -> {}", crash.case.day, crash.panic, path.display()),
+                Err(e) => error = Some(e),
+            }
+        }
+        if last_report.elapsed() > Duration::from_secs(10) {
+            last_report = Instant::now();
+            eprintln!("{} inputs, {} crashes ({} distinct) in {:.0}s", i, crashes, locations.len(), start.elapsed().as_secs_f64());
+        }
+    });
+    let _ = panic::take_hook();
+    if let Some(e) = error {
+        return Err(e);
+    }
+    eprintln!("{} inputs, {} crashes ({} distinct) in {:.0}s", iterations, crashes, locations.len(), start.elapsed().as_secs_f64());
+    Ok(crashes)
+}
+
+struct Report {
+    day: u32,
+    title: &'static str,
+    answers: Result<(Answer, Answer), String>,
+    // Whether the parser or a part panicked (the error or the detail of
+    // the answer giving the panic message).
+    panicked: bool,
+    time: Duration,
+    check: String,
+    // With --alloc, the allocations for parsing and the two parts, with their times.
+    alloc: Option<[(Duration, AllocStats); 3]>,
+}
+
+// The parameters of a day, with the overrides that apply to it.
+fn day_params(solution: &dyn AnySolution, overrides: &[(String, String)]) -> Params {
+    let mut params = Params::new(solution.params());
+    for (name, value) in overrides {
+        if solution.params().iter().any(|p| p.name == name) {
+            params.set(name, value).unwrap();
+        }
+    }
+    params
+}
+
+fn print_params(days: &[u32]) {
+    let params: Vec<_> = days.iter().flat_map(|&day| days::get(day).unwrap().params().iter().map(move |p| (day, p))).collect();
+    let w0 = params.iter().map(|(_, p)| p.name.len()).chain([4]).max().unwrap();
+    let w1 = params.iter().map(|(_, p)| p.default.len()).chain([7]).max().unwrap();
+    println!("Day {:w0$} | {:w1$} | Description", "Name", "Default");
+    for (day, p) in params {
+        println!(" {:02} {:w0$} | {:w1$} | {}", day, p.name, p.default, p.help);
+    }
+}
+
+enum Task {
+    Parse(usize),
+    Part(usize, u32),
+}
+
+// What is known about a day while its tasks are running.
+#[derive(Default)]
+struct Progress {
+    error: Option<String>,
+    panicked: bool,
+    part1: Answer,
+    part2: Answer,
+    phases: [(Duration, AllocStats); 3],
+}
+
+// Runs f, returning the message of the panic (first line only) if it
+// panics, so that a day failing does not stop the others.
+fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        let message = fuzz::panic_message(payload.as_ref());
+        format!("panicked: {}", message.lines().next().unwrap_or_default())
+    })
+}
+
+// Runs the selected days on a pool of threads: each day is parsed, then
+// its two parts are run as separate tasks. Returns the reports in day
+// order, where the time of each day is the sum of the times of its tasks,
+// and the wall-clock time.
+fn run_days(options: &Options) -> (Vec<Report>, Duration) {
+    let solutions: Vec<&dyn AnySolution> = options.days.iter().map(|&day| days::get(day).unwrap()).collect();
+    let parsed: Vec<OnceLock<ParsedInput>> = solutions.iter().map(|_| OnceLock::new()).collect();
+    let progress: Vec<Mutex<Progress>> = solutions.iter().map(|_| Mutex::default()).collect();
+
+    let now = Instant::now();
+    pool::run(options.jobs, (0..solutions.len()).map(Task::Parse), |task, queue| match task {
+        Task::Parse(i) => {
+            let solution = solutions[i];
+            let input = match options.input.read(solution.day()) {
+                Ok(input) => input,
+                Err(e) => {
+                    progress[i].lock().unwrap().error = Some(format!("cannot read input: {}", e));
+                    return;
+                },
+            };
+            let start = Instant::now();
+            let (result, alloc) = memory::measure(|| {
+                catch_panic(|| solution.parse_input(&input, &day_params(solution, &options.params)))
+            });
+            let mut progress = progress[i].lock().unwrap();
+            progress.phases[0] = (start.elapsed(), alloc);
+            match result {
+                Ok(Ok(input)) => {
+                    parsed[i].set(input).ok();
+                    queue.schedule(Task::Part(i, 2));
+                    queue.schedule(Task::Part(i, 1));
+                },
+                Ok(Err(e)) => progress.error = Some(format!("invalid input: {}", e)),
+                Err(panic) => {
+                    progress.error = Some(panic);
+                    progress.panicked = true;
+                },
+            }
+        },
+        Task::Part(i, part) => {
+            let input = parsed[i].get().unwrap().as_ref();
+            let start = Instant::now();
+            let (answer, alloc) = memory::measure(|| catch_panic(|| match part {
+                1 => solutions[i].run_part1(input),
+                _ => solutions[i].run_part2(input),
+            }));
+            let mut progress = progress[i].lock().unwrap();
+            progress.phases[part as usize] = (start.elapsed(), alloc);
+            let answer = answer.unwrap_or_else(|panic| {
+                progress.panicked = true;
+                Answer::Unsolved.with_detail(panic)
+            });
+            match part {
+                1 => progress.part1 = answer,
+                _ => progress.part2 = answer,
+            }
+        },
+    });
+    let wall_time = now.elapsed();
+
+    let reports = solutions.iter().zip(progress)
+        .map(|(solution, progress)| {
+            let progress = progress.into_inner().unwrap();
+            Report{
+                day: solution.day(),
+                title: solution.title(),
+                answers: match progress.error {
+                    Some(e) => Err(e),
+                    None => Ok((progress.part1, progress.part2)),
+                },
+                panicked: progress.panicked,
+                time: progress.phases.iter().map(|p| p.0).sum(),
+                check: String::new(),
+                alloc: if options.alloc { Some(progress.phases) } else { None },
+            }
+        })
+        .collect();
+    (reports, wall_time)
+}
+
+// Compares the answers of a day with the recorded ones, filling in the
+// check column; returns false on mismatch.
+fn check_answers(report: &mut Report, recorded: &Answers) -> bool {
+    let expected = (recorded.get(report.day, 1), recorded.get(report.day, 2));
+    let (ok, check) = match (&report.answers, expected) {
+        (_, (None, None)) => (true, String::from("not recorded")),
+        (Err(_), _) => (false, String::from("FAILED")),
+        (Ok((part1, part2)), (exp1, exp2)) => {
+            let mut wrong = Vec::new();
+            if exp1.is_some_and(|e| e != part1.value().to_string()) {
+                wrong.push(format!("part 1 should be {}", exp1.unwrap()));
+            }
+            if exp2.is_some_and(|e| e != part2.value().to_string()) {
+                wrong.push(format!("part 2 should be {}", exp2.unwrap()));
+            }
+            if wrong.is_empty() {
+                (true, String::from("ok"))
+            } else {
+                (false, format!("WRONG: {}", wrong.join(", ")))
+            }
+        },
+    };
+    report.check = check;
+    ok
+}
+
+fn read_answers(path: &str) -> Result<Answers, String> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => Answers::create_from(&text).map_err(|e| format!("invalid answers file {}: {}", path, e)),
+        Err(e) => Err(format!("cannot read {}: {}", path, e)),
+    }
+}
+
+fn format_time(t: Duration) -> String {
+    format!("{:.3}ms", t.as_secs_f64() * 1000.0)
+}
+
+fn print_table(reports: &[Report], wall_time: Duration) {
+    // Errors are shown in place of the answers.
+    let answers: Vec<(String, String)> = reports.iter()
+        .map(|r| match &r.answers {
+            Ok((part1, part2)) => (part1.to_string(), part2.to_string()),
+            Err(e) => (e.clone(), String::new()),
+        })
+        .collect();
+    let total: Duration = reports.iter().map(|r| r.time).sum();
+    let w0 = reports.iter().map(|r| r.title.len()).max().unwrap_or(0);
+    let w1 = answers.iter().map(|a| a.0.len()).chain([6]).max().unwrap();
+    let w2 = answers.iter().map(|a| a.1.len()).chain([6]).max().unwrap();
+    let wt = reports.iter().map(|r| format_time(r.time).len()).chain([format_time(total).len()]).max().unwrap();
+    let w = w0 + w1 + w2 + 10;
+    // The check column is only shown when checking the answers.
+    let wc = reports.iter().map(|r| r.check.len()).chain([5]).max().unwrap();
+    let with_check = reports.iter().any(|r| !r.check.is_empty());
+    let check = |s: &str| if with_check { format!(" | {}", s) } else { String::new() };
+    let separator = format!("----{:-<w0$}-+-{:-<w1$}-+-{:-<w2$}-+-{:-<wt$}{}", "", "", "", "",
+        if with_check { format!("-+-{:-<wc$}", "") } else { String::new() });
+
+    println!("Day {:w0$} | {:w1$} | {:w2$} | {:>wt$}{}", "", "Part 1", "Part 2", "Time", check("Check"));
+    println!("{}", separator);
+    for (r, (part1, part2)) in reports.iter().zip(answers) {
+        println!(" {:02} {:w0$} | {:w1$} | {:w2$} | {:>wt$}{}", r.day, r.title, part1, part2, format_time(r.time), check(&r.check));
+    }
+    println!("{}", separator);
+    println!("{:w$} | {:>wt$}", "Total (CPU time)", format_time(total));
+    println!("{:w$} | {:>wt$}", "Wall-clock time", format_time(wall_time));
+}
+
+// Prints the allocations of each phase, as measured with --alloc.
+fn print_alloc_table(reports: &[Report]) {
+    println!("Day Phase | {:>12} | {:>12} | {:>12} | {:>12}", "Time", "Allocations", "Allocated", "Peak");
+    println!("----------+-{:-<12}-+-{:-<12}-+-{:-<12}-+-{:-<12}", "", "", "", "");
+    for r in reports {
+        for (phase, (time, alloc)) in bench::PHASES.iter().zip(r.alloc.iter().flatten()) {
+            println!(" {:02} {:5} | {:>12} | {:>12} | {:>12} | {:>12}", r.day, phase, format_time(*time),
+                alloc.allocations, memory::format_bytes(alloc.bytes), memory::format_bytes(alloc.peak));
+        }
+    }
+}
+
+fn alloc_json(phases: &[(Duration, AllocStats); 3]) -> Value {
+    Value::object(bench::PHASES.iter().zip(phases).map(|(phase, (time, alloc))| {
+        (*phase, Value::object([
+            ("time_ns", Value::Int(time.as_nanos() as i64)),
+            ("allocations", Value::Int(alloc.allocations as i64)),
+            ("bytes", Value::Int(alloc.bytes as i64)),
+            ("peak_bytes", Value::Int(alloc.peak as i64)),
+        ]))
+    }))
+}
+
+// The status of a day: "ok", "error" for an invalid input, or "panicked".
+fn status(report: &Report) -> &'static str {
+    match report {
+        Report{ panicked: true, .. } => "panicked",
+        Report{ answers: Err(_), .. } => "error",
+        _ => "ok",
+    }
+}
+
+fn reports_json(reports: &[Report], wall_time: Duration) -> Value {
+    let total: Duration = reports.iter().map(|r| r.time).sum();
+    let days = reports.iter()
+        .map(|r| {
+            let (answers, details, error) = match &r.answers {
+                Ok((part1, part2)) => (
+                    (part1.to_json(), part2.to_json()),
+                    (Value::from(part1.detail()), Value::from(part2.detail())),
+                    Value::Null,
+                ),
+                Err(e) => ((Value::Null, Value::Null), (Value::Null, Value::Null), Value::str(e)),
+            };
+            Value::object([
+                ("day", Value::Int(r.day as i64)),
+                ("title", Value::str(r.title)),
+                ("status", Value::str(status(r))),
+                ("part1", answers.0),
+                ("part2", answers.1),
+                ("part1_detail", details.0),
+                ("part2_detail", details.1),
+                ("time_ns", Value::Int(r.time.as_nanos() as i64)),
+                ("error", error),
+                ("check", if r.check.is_empty() { Value::Null } else { Value::str(&r.check) }),
+                ("alloc", Value::from(r.alloc.as_ref().map(alloc_json))),
+            ])
+        })
+        .collect();
+    Value::object([
+        ("days", Value::Array(days)),
+        ("cpu_time_ns", Value::Int(total.as_nanos() as i64)),
+        ("wall_time_ns", Value::Int(wall_time.as_nanos() as i64)),
+    ])
+}
+
+fn bench_days(options: &Options) -> Result<Vec<DayBench>, String> {
+    let mut results = Vec::new();
+    for &day in &options.days {
+        let input = options.input.read(day).map_err(|e| format!("day {:02}: cannot read input: {}", day, e))?;
+        eprint!("Benchmarking day {:02}...\r", day);
+        let solution = days::get(day).unwrap();
+        let params = day_params(solution, &options.params);
+        let result = bench::bench_day(solution, &input, &params, options.warmup, options.runs)
+            .map_err(|e| format!("invalid input: {}", e))?;
+        results.push(result);
+    }
+    Ok(results)
+}
+
+// The change (in percent) of each phase with respect to the baseline, if any.
+fn compare(results: &[DayBench], baseline: Option<&bench::Baseline>) -> Vec<[Option<f64>; 3]> {
+    results.iter()
+        .map(|r| {
+            let mut changes = [None; 3];
+            for (i, phase) in bench::PHASES.iter().enumerate() {
+                changes[i] = baseline
+                    .and_then(|b| b.get(&(r.day, phase.to_string())))
+                    .map(|base| bench::change(base, &r.phases[i]) * 100.0);
+            }
+            changes
+        })
+        .collect()
+}
+
+fn print_bench(results: &[DayBench], changes: &[[Option<f64>; 3]], threshold: f64) {
+    println!("Day Phase | {:>12} | {:>12} | {:>12} | {:>12} | Baseline", "Min", "Median", "Mean", "Stddev");
+    println!("----------+-{:-<12}-+-{:-<12}-+-{:-<12}-+-{:-<12}-+---------", "", "", "", "");
+    for (r, changes) in results.iter().zip(changes) {
+        for ((phase, st), change) in bench::PHASES.iter().zip(r.phases.iter()).zip(changes) {
+            let comparison = match change {
+                Some(change) if *change > threshold => format!("{:+.1}% REGRESSION", change),
+                Some(change) => format!("{:+.1}%", change),
+                None => String::from("-"),
+            };
+            println!(" {:02} {:5} | {:>12} | {:>12} | {:>12} | {:>12} | {}", r.day, phase,
+                bench::format_ns(st.min), bench::format_ns(st.median),
+                bench::format_ns(st.mean), bench::format_ns(st.stddev), comparison);
+        }
+    }
+}
+
+fn bench_json(results: &[DayBench], changes: &[[Option<f64>; 3]], threshold: f64) -> Value {
+    let mut phases = Vec::new();
+    for (r, changes) in results.iter().zip(changes) {
+        for ((phase, st), change) in bench::PHASES.iter().zip(r.phases.iter()).zip(changes) {
+            phases.push(Value::object([
+                ("day", Value::Int(r.day as i64)),
+                ("phase", Value::str(phase)),
+                ("min_ns", Value::Float(st.min)),
+                ("median_ns", Value::Float(st.median)),
+                ("mean_ns", Value::Float(st.mean)),
+                ("stddev_ns", Value::Float(st.stddev)),
+                ("change_percent", Value::from(change.map(Value::Float))),
+                ("regression", Value::Bool(change.is_some_and(|c| c > threshold))),
+            ]));
+        }
+    }
+    Value::object([("bench", Value::Array(phases))])
+}
+
+fn run_bench(options: &Options) -> Result<usize, String> {
+    // Read the baseline first, as it might be the file about to be overwritten.
+    let baseline = match &options.baseline {
+        Some(path) => {
+            let text = std::fs::read_to_string(path).map_err(|e| format!("cannot read {}: {}", path, e))?;
+            Some(bench::from_text(&text).map_err(|e| format!("invalid baseline {}: {}", path, e))?)
+        },
+        None => None,
+    };
+
+    let results = bench_days(options)?;
+    let changes = compare(&results, baseline.as_ref());
+    if options.json {
+        println!("{}", bench_json(&results, &changes, options.threshold));
+    } else {
+        print_bench(&results, &changes, options.threshold);
+    }
+    std::fs::write(&options.output, bench::to_text(&results))
+        .map_err(|e| format!("cannot write {}: {}", options.output, e))?;
+    eprintln!("Results saved to {}", options.output);
+    Ok(changes.iter().flatten().filter(|c| c.is_some_and(|c| c > options.threshold)).count())
+}
+
+fn generate_main(args: &[String]) {
+    if args.iter().any(|a| a == "-h" || a == "--help") {
+        println!("{}", GENERATE_USAGE);
+        return;
+    }
+    let options = match parse_generate_args(args) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, GENERATE_USAGE);
+            std::process::exit(2);
+        },
+    };
+    if let Err(e) = run_generate(&options) {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
+
+fn fuzz_main(args: &[String]) {
+    if args.iter().any(|a| a == "-h" || a == "--help") {
+        println!("{}", FUZZ_USAGE);
+        return;
+    }
+    let options = match parse_fuzz_args(args) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, FUZZ_USAGE);
+            std::process::exit(2);
+        },
+    };
+    match run_fuzz(&options) {
+        Ok(0) => {},
+        Ok(_) => std::process::exit(1),
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        },
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().is_some_and(|a| a == "generate") {
+        generate_main(&args[1..]);
+        return;
+    }
+    if args.first().is_some_and(|a| a == "fuzz") {
+        fuzz_main(&args[1..]);
+        return;
+    }
+    if args.iter().any(|a| a == "-h" || a == "--help") {
+        println!("{}", USAGE);
+        return;
+    }
+
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            std::process::exit(2);
+        },
+    };
+
+    let tracing = match &options.trace {
+        Some(spec) => trace::configure(spec),
+        None => trace::configure_from_env(),
+    };
+    if let Err(e) = tracing {
+        eprintln!("{}", e);
+        std::process::exit(2);
+    }
+
+    if options.list_params {
+        print_params(&options.days);
+        return;
+    }
+
+    if options.bench {
+        match run_bench(&options) {
+            Ok(0) => {},
+            Ok(n) => {
+                eprintln!("{} regression(s) above {}%", n, options.threshold);
+                std::process::exit(1);
+            },
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            },
+        }
+        return;
+    }
+
+    // Read the recorded answers before spending time on the solutions.
+    let mut recorded = Answers::default();
+    if options.check || (options.record && std::path::Path::new(&options.answers).exists()) {
+        recorded = read_answers(&options.answers).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        });
+    }
+
+    if options.alloc {
+        memory::enable();
+    }
+    let (mut reports, wall_time) = run_days(&options);
+
+    // A panic fails the run, whatever the other options.
+    let mut failed = reports.iter().any(|r| r.panicked);
+    if options.check {
+        for r in reports.iter_mut() {
+            failed |= !check_answers(r, &recorded);
+        }
+    }
+    if options.record {
+        for r in &reports {
+            match &r.answers {
+                Ok((part1, part2)) => {
+                    // Unsolved parts are not recorded.
+                    for (part, answer) in [(1, part1), (2, part2)] {
+                        if answer.is_solved() {
+                            recorded.set(r.day, part, &answer.value().to_string());
+                        }
+                    }
+                },
+                Err(_) => failed = true,
+            }
+        }
+    }
+
+    if options.json {
+        println!("{}", reports_json(&reports, wall_time));
+    } else {
+        print_table(&reports, wall_time);
+        if options.alloc {
+            println!();
+            print_alloc_table(&reports);
+        }
+    }
+
+    if options.record {
+        if let Err(e) = std::fs::write(&options.answers, recorded.to_text()) {
+            eprintln!("cannot write {}: {}", options.answers, e);
+            std::process::exit(1);
+        }
+        eprintln!("Answers recorded in {}{}", options.answers, if failed { " (except for failed days)" } else { "" });
+    }
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn day_selection() {
+        assert_eq!(parse_days(&args("3 11 17-20")), Ok(vec![3, 11, 17, 18, 19, 20]));
+        assert_eq!(parse_days(&args("5 1-3 2")), Ok(vec![1, 2, 3, 5]));
+        assert_eq!(parse_days(&args("all")).map(|v| v.len()), Ok(25));
+        assert_eq!(parse_days(&args("")).map(|v| v.len()), Ok(25));
+        assert!(parse_days(&args("0")).is_err());
+        assert!(parse_days(&args("26")).is_err());
+        assert!(parse_days(&args("5-3")).is_err());
+        assert!(parse_days(&args("x")).is_err());
+    }
+
+    #[test]
+    fn options() {
+        let options = parse_args(&args("--bench --runs 50 3-4 --baseline base.txt")).unwrap();
+        assert!(options.bench);
+        assert_eq!((options.runs, options.warmup), (50, 2));
+        assert_eq!(options.baseline.as_deref(), Some("base.txt"));
+        assert_eq!(options.output, "bench_output.txt");
+        assert_eq!(options.days, vec![3, 4]);
+        assert!(parse_args(&args("--runs")).is_err());
+        assert!(parse_args(&args("--runs x")).is_err());
+        assert!(parse_args(&args("--runs 0")).is_err());
+        assert!(parse_args(&args("--frobnicate")).is_err());
+        assert!(parse_args(&args("--check --record")).is_err());
+
+        assert_eq!(parse_args(&args("1-3")).unwrap().input, Source::default());
+        assert_eq!(parse_args(&args("--input-dir bob 1-3")).unwrap().input, Source::Dir("bob".into()));
+        assert_eq!(parse_args(&args("--input - 5")).unwrap().input, Source::Stdin);
+        assert_eq!(parse_args(&args("7 --input x.txt")).unwrap().input, Source::File("x.txt".into()));
+        assert!(parse_args(&args("--input x.txt 5-6")).is_err());
+    }
+
+    #[test]
+    fn generate_options() {
+        let options = parse_generate_args(&args("--scale 0.5 --seed 7 --output-dir gen 1-3")).unwrap();
+        assert_eq!((options.scale, options.seed, options.days), (0.5, 7, vec![1, 2, 3]));
+        assert_eq!(options.output_dir, Some(PathBuf::from("gen")));
+        assert_eq!(parse_generate_args(&args("20")).unwrap().seed, 2020);
+        assert!(parse_generate_args(&args("1-3")).is_err());
+        assert!(parse_generate_args(&args("--scale 0 5")).is_err());
+        assert!(parse_generate_args(&args("--scale x 5")).is_err());
+        assert!(parse_generate_args(&args("--input x 5")).is_err());
+    }
+
+    #[test]
+    fn fuzz_options() {
+        let options = parse_fuzz_args(&args("--seed 7 --iterations 1000 --crashers out 8 19-20")).unwrap();
+        assert_eq!((options.days, options.seed, options.iterations), (vec![8, 19, 20], 7, Some(1000)));
+        assert_eq!(options.crashers, PathBuf::from("out"));
+        let options = parse_fuzz_args(&args("")).unwrap();
+        assert_eq!((options.days.len(), options.iterations), (25, None));
+        assert_eq!(options.fixtures, PathBuf::from("fixtures"));
+        assert!(parse_fuzz_args(&args("--iterations x")).is_err());
+        assert!(parse_fuzz_args(&args("--scale 2")).is_err());
+    }
+
+    #[test]
+    fn checks() {
+        let recorded = Answers::create_from("01.1 10\n01.2 20\n02.1 5\n").unwrap();
+        let report = |day, answers: Result<(i64, &str), &str>| Report{
+            day,
+            title: "",
+            answers: answers.map(|(a, b)| (Answer::from(a), Answer::from(b))).map_err(String::from),
+            panicked: false,
+            time: Duration::ZERO,
+            check: String::new(),
+            alloc: None,
+        };
+
+        let mut r = report(1, Ok((10, "20")));
+        assert!(check_answers(&mut r, &recorded));
+        assert_eq!(r.check, "ok");
+        let mut r = report(1, Ok((10, "21")));
+        assert!(!check_answers(&mut r, &recorded));
+        assert_eq!(r.check, "WRONG: part 2 should be 20");
+        let mut r = report(2, Ok((5, "anything")));
+        assert!(check_answers(&mut r, &recorded));
+        let mut r = report(1, Err("invalid input"));
+        assert!(!check_answers(&mut r, &recorded));
+        let mut r = report(3, Ok((1, "2")));
+        assert!(check_answers(&mut r, &recorded));
+        assert_eq!(r.check, "not recorded");
+
+        // The details are not part of the answers.
+        let mut r = report(1, Ok((10, "20")));
+        r.answers = r.answers.map(|(a, b)| (a.with_detail("player 1 wins"), b));
+        assert!(check_answers(&mut r, &recorded));
+        r.answers = r.answers.map(|(_, b)| (Answer::Unsolved, b));
+        assert!(!check_answers(&mut r, &recorded));
+        assert_eq!(r.check, "WRONG: part 1 should be 10");
+    }
+
+    #[test]
+    fn json() {
+        let reports = [
+            Report{ day: 21, title: "T", answers: Ok((Answer::from(1).with_detail("d"), Answer::from("a,b"))), panicked: false, time: Duration::from_nanos(5), check: String::new(), alloc: None },
+            Report{ day: 22, title: "U", answers: Err("bad \"x\"".to_string()), panicked: false, time: Duration::from_nanos(7), check: String::from("FAILED"), alloc: None },
+            Report{ day: 23, title: "V", answers: Ok((Answer::from(2), Answer::Unsolved.with_detail("panicked: oops"))), panicked: true, time: Duration::ZERO, check: String::new(), alloc: None },
+        ];
+        assert_eq!(reports_json(&reports, Duration::from_nanos(9)).to_string(), concat!(
+            r#"{"days":[{"day":21,"title":"T","status":"ok","part1":"1","part2":"a,b","part1_detail":"d","part2_detail":null,"time_ns":5,"error":null,"check":null,"alloc":null},"#,
+            r#"{"day":22,"title":"U","status":"error","part1":null,"part2":null,"part1_detail":null,"part2_detail":null,"time_ns":7,"error":"bad \"x\"","check":"FAILED","alloc":null},"#,
+            r#"{"day":23,"title":"V","status":"panicked","part1":"2","part2":null,"part1_detail":null,"part2_detail":"panicked: oops","time_ns":0,"error":null,"check":null,"alloc":null}],"#,
+            r#""cpu_time_ns":12,"wall_time_ns":9}"#));
+    }
+}
@@ -0,0 +1,85 @@
+use crate::answers::Answer;
+use crate::error::{parse_number, ParseError};
+use crate::params::{Param, Params};
+use crate::parser;
+use crate::solution::Solution;
+
+// Two entries summing to the target, in the sorted entries.
+fn find_pair(v: &[i64], target: i64) -> Option<(i64, i64)> {
+    v.iter().enumerate().find_map(|(i, &a)| {
+        let b = target.checked_sub(a)?;
+        v[i+1..].binary_search(&b).ok().map(|_| (a, b))
+    })
+}
+
+// Three entries summing to the target, in the sorted entries.
+fn find_triple(v: &[i64], target: i64) -> Option<(i64, i64, i64)> {
+    v.iter().enumerate().find_map(|(i, &a)| {
+        let (b, c) = find_pair(&v[i+1..], target.checked_sub(a)?)?;
+        Some((a, b, c))
+    })
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "Report Repair";
+
+    const PARAMS: &'static [Param] = &[
+        Param{ name: "target", default: "2020", help: "the sum the entries must add up to" },
+    ];
+
+    // The sorted entries and the target sum.
+    type Input = (Vec<i64>, i64);
+    type Answer1 = Answer;
+    type Answer2 = Answer;
+
+    fn parse(input: &str) -> Result<(Vec<i64>, i64), ParseError> {
+        Self::parse_with(input, &Params::new(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<(Vec<i64>, i64), ParseError> {
+        let mut v: Vec<i64> = parser::lines(input, parse_number)?;
+        v.sort();
+        Ok((v, params.get("target")?))
+    }
+
+    fn part1((v, target): &(Vec<i64>, i64)) -> Answer {
+        match find_pair(v, *target) {
+            Some((a, b)) => Answer::from(a as i128 * b as i128),
+            None => Answer::Unsolved.with_detail("no two entries sum to the target"),
+        }
+    }
+
+    fn part2((v, target): &(Vec<i64>, i64)) -> Answer {
+        match find_triple(v, *target) {
+            Some((a, b, c)) => (a as i128 * b as i128).checked_mul(c as i128)
+                .map_or(Answer::Unsolved.with_detail("the product overflows"), Answer::from),
+            None => Answer::Unsolved.with_detail("no three entries sum to the target"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unsolved() {
+        let input = Day01::parse("1721\n979\n366\n299\n675\n1456").unwrap();
+        assert_eq!((Day01::part1(&input), Day01::part2(&input)), (Answer::Int(514579), Answer::Int(241861950)));
+        for text in ["1", "1000\n1021", "1\n2\n3"] {
+            let input = Day01::parse(text).unwrap();
+            assert!(!Day01::part1(&input).is_solved() && !Day01::part2(&input).is_solved(), "{:?}", text);
+        }
+
+        // no overflow on extreme entries, whose product is given in full
+        let extremes = format!("{}\n{}", i64::MIN, i64::MAX);
+        assert!(!Day01::part1(&Day01::parse(&extremes).unwrap()).is_solved());
+        let mut params = Params::new(Day01::PARAMS);
+        params.set("target", "-1").unwrap();
+        let input = Day01::parse_with(&extremes, &params).unwrap();
+        assert_eq!(Day01::part1(&input), Answer::BigInt(i64::MIN as i128 * i64::MAX as i128));
+    }
+}
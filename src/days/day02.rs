@@ -0,0 +1,80 @@
+use crate::error::ParseError;
+use crate::parser::{self, Scanner};
+use crate::solution::Solution;
+
+pub struct DatabaseEntry {
+    policy: (usize, usize, char),
+    password: String,
+}
+
+impl DatabaseEntry {
+    fn create_from(s: &str) -> Result<DatabaseEntry, ParseError> {
+        let (policy, password) = parser::key_value(s, ": ")?;
+        let mut sc = Scanner::new(policy);
+        let n1: usize = sc.integer()?;
+        sc.literal("-")?;
+        let n2: usize = sc.integer()?;
+        sc.literal(" ")?;
+        let c = sc.char()?;
+        sc.end()?;
+        if n1 == 0 || n1 > n2 {
+            return Err(ParseError::new("invalid policy range", policy));
+        }
+
+        Ok(DatabaseEntry {
+            policy: (n1, n2, c),
+            password: password.to_string()
+        })
+    }
+
+    fn is_valid1(&self) -> bool {
+        let (min, max, c) = self.policy;
+        let n = self.password.chars().filter(|x| *x == c).count();
+        n >= min && n <= max
+    }
+
+    // A position past the end of the password does not hold the letter.
+    fn is_valid2(&self) -> bool {
+        let (p1, p2, c) = self.policy;
+        (self.password.chars().nth(p1-1) == Some(c))
+            != (self.password.chars().nth(p2-1) == Some(c))
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    const TITLE: &'static str = "Password Philosophy";
+
+    type Input = Vec<DatabaseEntry>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<DatabaseEntry>, ParseError> {
+        parser::lines(input, DatabaseEntry::create_from)
+    }
+
+    fn part1(v: &Vec<DatabaseEntry>) -> usize {
+        v.iter().filter(|p| p.is_valid1()).count()
+    }
+
+    fn part2(v: &Vec<DatabaseEntry>) -> usize {
+        v.iter().filter(|p| p.is_valid2()).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions() {
+        let v = Day02::parse("1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc").unwrap();
+        assert_eq!((Day02::part1(&v), Day02::part2(&v)), (2, 1));
+        let v = Day02::parse("1-9 a: ab\n2-9 a: ab\n1-2 a: a\n20-30 z: zz").unwrap();
+        assert_eq!((Day02::part1(&v), Day02::part2(&v)), (2, 2));
+        assert!(Day02::parse("0-2 a: ab").is_err());
+        assert!(Day02::parse("3-2 a: ab").is_err());
+    }
+}
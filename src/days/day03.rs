@@ -0,0 +1,80 @@
+use crate::error::ParseError;
+use crate::grid::{consts::ORIGIN, Direction, SimpleGrid, Topology};
+use crate::params::{Param, Params};
+use crate::parser::{self, Scanner};
+use crate::solution::Solution;
+
+// The map (repeating to the right), together with the slope for part 1 and the slopes for part 2,
+// as (right, down).
+pub struct Trajectories {
+    map: SimpleGrid,
+    slope: (usize, usize),
+    slopes: Vec<(usize, usize)>,
+}
+
+// Reads a list of slopes such as "1:1,3:1", as (right, down).
+fn read_slopes(s: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+    parser::list(s, ",", |slope| {
+        let mut sc = Scanner::new(slope);
+        let right = sc.integer()?;
+        sc.literal(":")?;
+        let down = sc.integer()?;
+        sc.end()?;
+        if down == 0 {
+            return Err(ParseError::new("the slope must go down", slope));
+        }
+        Ok((right, down))
+    })
+}
+
+fn trees_on_slope( grid: &SimpleGrid, slope_right: usize, slope_down: usize ) -> usize {
+    let slope = Direction{ dx: slope_right as i64, dy: slope_down as i64 };
+    let mut pos = ORIGIN;
+    let mut count = 0;
+    // wrapping the location at each step keeps it from growing
+    while let Some(p) = grid.wrap(&pos.go(&slope)) {
+        if grid.get_by_location(&p) == Some(&b'#') { count += 1; }
+        pos = p;
+    }
+    count
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    const TITLE: &'static str = "Toboggan Trajectory";
+
+    const PARAMS: &'static [Param] = &[
+        Param{ name: "slope", default: "3:1", help: "the slope of part 1, as RIGHT:DOWN" },
+        Param{ name: "slopes", default: "1:1,3:1,5:1,7:1,1:2", help: "the slopes of part 2" },
+    ];
+
+    type Input = Trajectories;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Trajectories, ParseError> {
+        Self::parse_with(input, &Params::new(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Trajectories, ParseError> {
+        let map = SimpleGrid::create_from(input)?.with_topology(Topology::WrapHorizontal);
+        let slope = match read_slopes(params.get_str("slope"))?[..] {
+            [slope] => slope,
+            _ => return Err(ParseError::new("expected a single slope", params.get_str("slope"))),
+        };
+        let slopes = read_slopes(params.get_str("slopes"))?;
+        Ok(Trajectories{ map, slope, slopes })
+    }
+
+    fn part1(t: &Trajectories) -> usize {
+        trees_on_slope(&t.map, t.slope.0, t.slope.1)
+    }
+
+    fn part2(t: &Trajectories) -> usize {
+        t.slopes.iter()
+            .map(|&(right, down)| trees_on_slope(&t.map, right, down))
+            .product()
+    }
+}
@@ -0,0 +1,111 @@
+use crate::error::ParseError;
+use crate::parser;
+use crate::solution::Solution;
+use std::collections::HashMap;
+
+pub struct Passport {
+    fields: HashMap<String, String>,
+}
+
+impl Passport {
+    fn create_from(s: &str) -> Result<Passport, ParseError> {
+        let fields = s.split_ascii_whitespace()
+                      .map(|field| parser::key_value(field, ":").map(|(k, v)| (k.to_string(), v.to_string())))
+                      .collect::<Result<_, _>>()?;
+        Ok(Passport { fields })
+    }
+
+    fn is_complete(&self) -> bool {
+        [ "byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid" ]
+            .iter().all(|&f| self.fields.contains_key(f))
+    }
+
+    fn is_valid(&self) -> bool {
+        if !self.is_complete() { return false; }
+        let pass = &self.fields;
+
+        // validate Birth Year
+        if !pass["byr"].parse::<u64>().ok()
+                       .is_some_and(|n| (1920..=2002).contains(&n)) {
+            return false;
+        }
+
+        // validate Issue Year
+        if !pass["iyr"].parse::<u64>().ok()
+                       .is_some_and(|n| (2010..=2020).contains(&n)) {
+            return false;
+        }
+
+        // validate Expiration Year
+        if !pass["eyr"].parse::<u64>().ok()
+                       .is_some_and(|n| (2020..=2030).contains(&n)) {
+            return false;
+        }
+
+        // validate Height
+        if let Some(hgt) = pass["hgt"].strip_suffix("cm") {
+            if !hgt.parse::<u64>().ok()
+                   .is_some_and(|n| (150..=193).contains(&n)) {
+                return false;
+            }
+        }
+        else if let Some(hgt) = pass["hgt"].strip_suffix("in") {
+            if !hgt.parse::<u64>().ok()
+                   .is_some_and(|n| (59..=76).contains(&n)) {
+                return false;
+            }
+        }
+        else {
+            return false;
+        }
+
+        // validate Hair Color
+        if !(match pass["hcl"].strip_prefix('#') {
+            Some(color) => {
+                color.len() == 6 && color.chars().all(|c| {
+                    c.is_ascii_digit() || ('a'..='f').contains(&c)
+                })
+            }
+            None => { false }
+        }) {
+            return false;
+        }
+
+        // validate Eye Color
+        if ![ "amb", "blu", "brn", "gry", "grn", "hzl", "oth" ].contains(&pass["ecl"].as_str()) {
+            return false;
+        }
+
+        // validate Passport ID
+        if !(pass["pid"].len() == 9 && pass["pid"].chars().all(|c| {
+            c.is_ascii_digit()
+        })) {
+            return false;
+        }
+
+        true
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+    const TITLE: &'static str = "Passport Processing";
+
+    type Input = Vec<Passport>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Passport>, ParseError> {
+        parser::sections(input).map(Passport::create_from).collect()
+    }
+
+    fn part1(batch: &Vec<Passport>) -> usize {
+        batch.iter().filter(|&p| p.is_complete()).count()
+    }
+
+    fn part2(batch: &Vec<Passport>) -> usize {
+        batch.iter().filter(|&p| p.is_valid()).count()
+    }
+}
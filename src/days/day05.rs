@@ -0,0 +1,78 @@
+use crate::answers::Answer;
+use crate::error::ParseError;
+use crate::parser;
+use crate::solution::Solution;
+
+fn seat(code: &str) -> Result<(usize, usize), ParseError> {
+    if code.len() != 10 {
+        return Err(ParseError::new("seat codes must have 10 letters", code));
+    }
+    let (mut row, mut col) = (0, 0);
+    for (i, c) in code.char_indices() {
+        match c {
+            'F' => { row <<= 1; },
+            'B' => { row <<= 1; row += 1; },
+            'L' => { col <<= 1; },
+            'R' => { col <<= 1; col += 1; },
+            _ => { return Err(ParseError::new("invalid seat letter", &code[i..])); },
+        }
+    }
+    Ok((row, col))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    const TITLE: &'static str = "Binary Boarding";
+
+    // The sorted list of seat IDs.
+    type Input = Vec<usize>;
+    type Answer1 = Answer;
+    type Answer2 = Answer;
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        let mut seat_ids: Vec<_> = parser::lines(input, |code| seat(code).map(|(row, col)| row*8+col))?;
+        seat_ids.sort();
+        Ok(seat_ids)
+    }
+
+    fn part1(seat_ids: &Vec<usize>) -> Answer {
+        seat_ids.last().copied().map_or(Answer::Unsolved.with_detail("no seats"), Answer::from)
+    }
+
+    // The first missing ID after the smallest one, which must be followed
+    // by an occupied seat.
+    fn part2(seat_ids: &Vec<usize>) -> Answer {
+        let min_id = seat_ids.first().copied().unwrap_or(0);
+        seat_ids
+            .iter()
+            .enumerate()
+            .find_map(|(pos, id)| if *id > min_id+pos { Some(min_id+pos) } else { None })
+            .map_or(Answer::Unsolved.with_detail("no free seat between occupied ones"), Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example01() {
+        assert_eq!(seat("FBFBBFFRLR"), Ok((44, 5)));
+        assert_eq!(seat("BFFFBBFRRR"), Ok((70, 7)));
+        assert_eq!(seat("FFFBBBFRRR"), Ok((14, 7)));
+        assert_eq!(seat("BBFFBBFRLL"), Ok((102, 4)));
+    }
+
+    #[test]
+    fn unsolved() {
+        let empty = Day05::parse("").unwrap();
+        assert!(!Day05::part1(&empty).is_solved() && !Day05::part2(&empty).is_solved());
+        let seats = Day05::parse("FFFFFFFLLL\nFFFFFFFLLR").unwrap();
+        assert_eq!(Day05::part1(&seats), Answer::Int(1));
+        assert!(!Day05::part2(&seats).is_solved());
+        let seats = Day05::parse("FFFFFFFLLR\nFFFFFFFLRR").unwrap();
+        assert_eq!(Day05::part2(&seats), Answer::Int(2));
+    }
+}
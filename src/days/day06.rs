@@ -0,0 +1,47 @@
+use crate::error::ParseError;
+use crate::parser;
+use crate::solution::Solution;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    const TITLE: &'static str = "Custom Customs";
+
+    // For each group, the size of the group and the frequency of "yes" answers.
+    type Input = Vec<(usize, [usize; 26])>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<(usize, [usize; 26])>, ParseError> {
+        parser::sections(input)
+            .map(|group| {
+                let mut group_size = 0;
+                let mut yes_answers = [0; 26];
+                for person in group.lines() {
+                    for (i, c) in person.bytes().enumerate() {
+                        match c {
+                            b'a'..=b'z' => { yes_answers[(c-b'a') as usize] += 1; },
+                            _ => { return Err(ParseError::new("invalid answer", &person[i..])); },
+                        }
+                    }
+                    group_size += 1;
+                }
+                Ok((group_size, yes_answers))
+            }).collect()
+    }
+
+    fn part1(yes_answers_by_group: &Vec<(usize, [usize; 26])>) -> usize {
+        yes_answers_by_group
+            .iter()
+            .map(|&(_, yes)| yes.iter().filter(|&n| *n > 0).count())
+            .sum()
+    }
+
+    fn part2(yes_answers_by_group: &Vec<(usize, [usize; 26])>) -> usize {
+        yes_answers_by_group
+            .iter()
+            .map(|&(size, yes)| yes.iter().filter(|&n| *n == size).count())
+            .sum()
+    }
+}
@@ -0,0 +1,68 @@
+use crate::error::ParseError;
+use crate::parser::{self, Scanner};
+use crate::solution::Solution;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    const TITLE: &'static str = "Handy Haversacks";
+
+    // For each bag color, the list of (number, color) of the bags it contains.
+    type Input = HashMap<String, Vec<(usize, String)>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<HashMap<String, Vec<(usize, String)>>, ParseError> {
+        let mut rules = HashMap::new();
+        for l in input.lines() {
+            let mut sc = Scanner::new(l);
+            let outer = sc.until(" bags contain ")?;
+            if sc.try_literal("no other bags.") {
+                sc.end()?;
+                rules.insert(outer.to_string(), vec![]);
+                continue;
+            }
+            let inner_list = sc.rest().strip_suffix('.').ok_or_else(|| sc.error("expected a list ending with '.'"))?;
+            let inner: Vec<_> = parser::list(inner_list, ", ", |s| {
+                let mut sc = Scanner::new(s);
+                let n = sc.integer()?;
+                sc.literal(" ")?;
+                let color = sc.until(" bag")?;
+                sc.try_literal("s");
+                sc.end()?;
+                Ok((n, color.to_string()))
+            })?;
+            rules.insert(outer.to_string(), inner);
+        }
+        Ok(rules)
+    }
+
+    fn part1(rules: &HashMap<String, Vec<(usize, String)>>) -> usize {
+        let mut can_contain = HashSet::new();
+        let mut target = vec!["shiny gold"];
+        while let Some(target_color) = target.pop() {
+            for (outer, inner) in rules {
+                if inner.iter().any(|(_, color)| target_color == color)
+                    && can_contain.insert(outer) {
+                    target.push(outer);
+                }
+            }
+        }
+        can_contain.len()
+    }
+
+    fn part2(rules: &HashMap<String, Vec<(usize, String)>>) -> usize {
+        let mut total_contained = 0;
+        let mut target = vec![(1, "shiny gold")];
+        while let Some((target_n, target_color)) = target.pop() {
+            for (n, color) in &rules[target_color] {
+                total_contained += target_n * n;
+                target.push((target_n * n, color));
+            }
+        }
+        total_contained
+    }
+}
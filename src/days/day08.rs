@@ -0,0 +1,147 @@
+use crate::error::ParseError;
+use crate::parser::{self, Scanner};
+use crate::solution::Solution;
+use crate::trace;
+use std::collections::HashSet;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum OpCode {
+    Acc,
+    Jmp,
+    Nop,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Instruction {
+    op: OpCode,
+    arg: i64,
+}
+
+impl Instruction {
+    fn create_from(s: &str) -> Result<Instruction, ParseError> {
+        let mut sc = Scanner::new(s);
+        let op = match sc.take_while(|c| c.is_ascii_alphabetic()) {
+            "acc" => OpCode::Acc,
+            "jmp" => OpCode::Jmp,
+            "nop" => OpCode::Nop,
+            _ => { return Err(ParseError::new("unknown operation", s)); },
+        };
+        sc.literal(" ")?;
+        let arg = sc.integer()?;
+        sc.end()?;
+        Ok(Instruction{ op, arg })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ExitStatus {
+    Completed(i64),
+    ErrorInfiniteLoop(i64),
+    ErrorInvalidJump,
+    ErrorOverflow,
+}
+
+fn execute(code: &[Instruction]) -> ExitStatus {
+    let mut accumulator = 0i64;
+    let mut ptr = 0usize;
+    let mut visited = HashSet::new();
+
+    while ptr < code.len() {
+        if !visited.insert(ptr) {
+            // infinite loop detected
+            return ExitStatus::ErrorInfiniteLoop(accumulator);
+        }
+
+        let Instruction{ op, arg } = code[ptr];
+        match op {
+            OpCode::Acc => {
+                match accumulator.checked_add(arg) {
+                    Some(a) => { accumulator = a; },
+                    None => { return ExitStatus::ErrorOverflow; },
+                }
+                ptr += 1;
+            },
+            OpCode::Jmp => {
+                let offset = usize::try_from(arg.unsigned_abs()).ok();
+                let target = if arg < 0 {
+                    offset.and_then(|o| ptr.checked_sub(o))
+                } else {
+                    offset.and_then(|o| ptr.checked_add(o))
+                };
+                match target {
+                    Some(p) => { ptr = p; },
+                    None => { return ExitStatus::ErrorInvalidJump; },
+                }
+            },
+            OpCode::Nop => {
+                ptr += 1;
+            }
+        }
+    }
+
+    ExitStatus::Completed(accumulator)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+    const TITLE: &'static str = "Handheld Halting";
+
+    type Input = Vec<Instruction>;
+    // None if the program does not loop, or if no change makes it terminate.
+    type Answer1 = Option<i64>;
+    type Answer2 = Option<i64>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        parser::lines(input, |s| Instruction::create_from(s.trim()))
+    }
+
+    fn part1(code: &Vec<Instruction>) -> Option<i64> {
+        match execute(code) {
+            ExitStatus::ErrorInfiniteLoop(res) => Some(res),
+            status => {
+                trace!(Info, "the program does not loop: {:?}", status);
+                None
+            },
+        }
+    }
+
+    fn part2(code: &Vec<Instruction>) -> Option<i64> {
+        for i in 0..code.len() {
+            let mut new_code = code.clone();
+
+            match new_code[i].op {
+                OpCode::Jmp => { new_code[i].op = OpCode::Nop; },
+                OpCode::Nop => { new_code[i].op = OpCode::Jmp; },
+                _ => { continue; },
+            }
+
+            trace!(Debug, "result after changing instruction {}: {:?}", i, execute(&new_code));
+
+            if let ExitStatus::Completed(res) = execute(&new_code) {
+                return Some(res);
+            }
+        }
+        trace!(Info, "no single change makes the program terminate");
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overflow() {
+        let code = Day08::parse(&format!("acc {}\nacc +1\njmp -2", i64::MAX)).unwrap();
+        assert_eq!(execute(&code), ExitStatus::ErrorOverflow);
+        assert_eq!((Day08::part1(&code), Day08::part2(&code)), (None, None));
+
+        let code = Day08::parse(&format!("nop +0\njmp {}\njmp {}", i64::MIN, i64::MAX)).unwrap();
+        assert_eq!(execute(&code), ExitStatus::ErrorInvalidJump);
+        let code = Day08::parse(&format!("acc +3\njmp -1\nnop {}", i64::MIN)).unwrap();
+        assert_eq!(execute(&code), ExitStatus::ErrorInfiniteLoop(3));
+        assert_eq!(Day08::part2(&code), Some(3));
+    }
+}
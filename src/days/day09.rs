@@ -0,0 +1,163 @@
+use crate::answers::Answer;
+use crate::error::{parse_number, ParseError};
+use crate::params::{Param, Params};
+use crate::parser;
+use crate::solution::Solution;
+
+#[derive(Debug)]
+pub struct XmasCode {
+    code: Vec<u64>,
+    window_len: usize,
+}
+
+impl XmasCode {
+    fn create_from(s: &str, window_len: usize) -> Result<XmasCode, ParseError> {
+        let code = parser::lines(s, parse_number)?;
+        Ok(XmasCode{ code, window_len })
+    }
+
+    fn is_valid_position(&self, pos: usize) -> bool {
+        assert!(pos >= self.window_len && pos < self.code.len());
+
+        for i in pos-self.window_len..pos-1 {
+            for j in i+1..pos {
+                if self.code[i].checked_add(self.code[j]) == Some(self.code[pos]) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    fn first_invalid_value(&self) -> Option<u64> {
+        let invalid_pos = (self.window_len..self.code.len()).find(|&i| !self.is_valid_position(i))?;
+        Some(self.code[invalid_pos])
+    }
+
+    // A range of at least two values adding up to the target.
+    fn find_range_with_sum(&self, target_sum: u64) -> Option<&[u64]> {
+        for i in 0..self.code.len() {
+            let mut sum = 0;
+            let mut j = i;
+            while sum <= target_sum && j < self.code.len() {
+                let Some(next) = sum.checked_add(self.code[j]) else { break };
+                sum = next;
+                j += 1;
+                if sum == target_sum && j - i >= 2 {
+                    return Some(&self.code[i..j]);
+                }
+            }
+        }
+        None
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+    const TITLE: &'static str = "Encoding Error";
+
+    const PARAMS: &'static [Param] = &[
+        Param{ name: "window", default: "25", help: "the length of the preamble" },
+    ];
+
+    type Input = XmasCode;
+    type Answer1 = Answer;
+    type Answer2 = Answer;
+
+    fn parse(input: &str) -> Result<XmasCode, ParseError> {
+        Self::parse_with(input, &Params::new(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<XmasCode, ParseError> {
+        let c = XmasCode::create_from(input, params.get("window")?)?;
+        // at least two values to sum, and at least one value to check
+        if c.window_len < 2 || c.window_len >= c.code.len() {
+            let message = format!("window must be at least 2 and less than the number of values ({})", c.code.len());
+            return Err(ParseError::new(message, params.get_str("window")));
+        }
+        Ok(c)
+    }
+
+    fn part1(c: &XmasCode) -> Answer {
+        c.first_invalid_value().map_or(Answer::Unsolved.with_detail("no invalid value"), Answer::from)
+    }
+
+    fn part2(c: &XmasCode) -> Answer {
+        let Some(invalid) = c.first_invalid_value() else {
+            return Answer::Unsolved.with_detail("no invalid value");
+        };
+        match c.find_range_with_sum(invalid) {
+            Some(range) => Answer::from(*range.iter().min().unwrap() as i128 + *range.iter().max().unwrap() as i128),
+            None => Answer::Unsolved.with_detail("no range adds up to the invalid value"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example01() {
+        let mut c = XmasCode{
+            code: vec![20, 1, 25, 2, 24, 3, 23, 4, 22, 5, 21, 6, 19, 7, 18, 8, 17, 9, 16, 10, 15, 11, 14, 12, 13, 0],
+            window_len: 25,
+        };
+        c.code[25] = 26;
+        assert!(c.is_valid_position(25), "{:?}: is_valid_position(25)? {}", c, c.is_valid_position(25));
+        c.code[25] = 49;
+        assert!(c.is_valid_position(25), "{:?}: is_valid_position(25)? {}", c, c.is_valid_position(25));
+        c.code[25] = 100;
+        assert!(!c.is_valid_position(25), "{:?}: is_valid_position(25)? {}", c, c.is_valid_position(25));
+        c.code[25] = 50;
+        assert!(!c.is_valid_position(25), "{:?}: is_valid_position(25)? {}", c, c.is_valid_position(25));
+    }
+
+    #[test]
+    fn example02() {
+        let mut c = XmasCode{
+            code: vec![20, 1, 25, 2, 24, 3, 23, 4, 22, 5, 21, 6, 19, 7, 18, 8, 17, 9, 16, 10, 15, 11, 14, 12, 13, 45, 0],
+            window_len: 25,
+        };
+        c.code[26] = 26;
+        assert!(c.is_valid_position(26), "{:?}: is_valid_position(26)? {}", c, c.is_valid_position(26));
+        c.code[26] = 65;
+        assert!(!c.is_valid_position(26), "{:?}: is_valid_position(26)? {}", c, c.is_valid_position(26));
+        c.code[26] = 64;
+        assert!(c.is_valid_position(26), "{:?}: is_valid_position(26)? {}", c, c.is_valid_position(26));
+        c.code[26] = 66;
+        assert!(c.is_valid_position(26), "{:?}: is_valid_position(26)? {}", c, c.is_valid_position(26));
+    }
+
+    #[test]
+    fn parameters() {
+        let input = "35\n20\n15\n25\n47\n40\n62\n55\n65\n95\n102\n117\n150\n182\n127\n219\n299\n277\n309\n576";
+        let mut params = Params::new(Day09::PARAMS);
+        params.set("window", "5").unwrap();
+        assert_eq!(Day09::part1(&Day09::parse_with(input, &params).unwrap()), Answer::Int(127));
+        params.set("window", "0").unwrap();
+        assert!(Day09::parse_with(input, &params).is_err());
+        params.set("window", "1").unwrap();
+        assert!(Day09::parse_with(input, &params).is_err());
+        params.set("window", "20").unwrap();
+        let e = Day09::parse_with(input, &params).unwrap_err();
+        assert_eq!(e.to_string(), "window must be at least 2 and less than the number of values (20) at '20'");
+        params.set("window", "19").unwrap();
+        assert!(Day09::parse_with(input, &params).is_ok());
+    }
+
+    #[test]
+    fn unsolved() {
+        let mut params = Params::new(Day09::PARAMS);
+        params.set("window", "2").unwrap();
+        let valid = Day09::parse_with("1\n2\n3\n5\n8", &params).unwrap();
+        assert!(!Day09::part1(&valid).is_solved() && !Day09::part2(&valid).is_solved());
+        let no_range = Day09::parse_with("1\n5\n7", &params).unwrap();
+        assert_eq!(Day09::part1(&no_range), Answer::Int(7));
+        assert!(!Day09::part2(&no_range).is_solved());
+        let huge = format!("{}\n{}\n{}", u64::MAX, u64::MAX, u64::MAX);
+        assert_eq!(Day09::part1(&Day09::parse_with(&huge, &params).unwrap()), Answer::from(u64::MAX));
+    }
+}
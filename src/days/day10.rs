@@ -0,0 +1,96 @@
+use crate::error::{parse_number, ParseError};
+use crate::parser;
+use crate::solution::Solution;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    const TITLE: &'static str = "Adapter Array";
+
+    // The sorted joltages, including the outlet and the device.
+    type Input = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        let mut v: Vec<i64> = parser::lines(input, |line| {
+            let joltage = parse_number(line)?;
+            if joltage > i64::MAX - 3 {
+                return Err(ParseError::new("joltage out of range", line));
+            }
+            Ok(joltage)
+        })?;
+
+        v.push(0);
+        v.sort();
+        v.push(v.last().unwrap()+3);
+        Ok(v)
+    }
+
+    fn part1(v: &Vec<i64>) -> i64 {
+        let mut count1 = 0;
+        let mut count3 = 0;
+        for i in 1..v.len() {
+            if v[i] - v[i-1] == 1 { count1 += 1; }
+            if v[i] - v[i-1] == 3 { count3 += 1; }
+        }
+        count1 * count3
+    }
+
+    fn part2(v: &Vec<i64>) -> i64 {
+        let mut paths = vec![0; v.len()];
+        paths[0] = 1;
+        for i in 1..v.len() {
+            // The number of different paths reaching step i (paths[i])
+            // is the sum of paths[p] for all p < i from which i can be
+            // reached in one step.
+            for p in i.saturating_sub(3)..i {
+                if v[i] - v[p] <= 3 { paths[i] += paths[p]; }
+            }
+        }
+        *paths.last().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::property::{self, Config};
+
+    // Counts the arrangements by trying every subset of the adapters.
+    fn naive_arrangements(v: &[i64]) -> i64 {
+        let middle = &v[1..v.len()-1];
+        let mut count = 0;
+        for subset in 0..1u32 << middle.len() {
+            let mut chain = vec![v[0]];
+            chain.extend(middle.iter().enumerate().filter(|(i, _)| subset >> i & 1 == 1).map(|(_, a)| *a));
+            chain.push(v[v.len()-1]);
+            if chain.windows(2).all(|w| (1..=3).contains(&(w[1] - w[0]))) {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    #[test]
+    fn arrangements_match_naive() {
+        let config = Config::default().max_size(14);
+        property::check(&config,
+            |rng, size| {
+                let mut joltage = 0;
+                let mut adapters: Vec<i64> = (0..rng.below(size) + 1).map(|_| {
+                    joltage += rng.range(1..=3);
+                    joltage
+                }).collect();
+                rng.shuffle(&mut adapters);
+                adapters
+            },
+            |adapters| property::shrink_vec(adapters, |_| vec![]),
+            |adapters| {
+                let text: String = adapters.iter().map(|a| format!("{}\n", a)).collect();
+                let v = Day10::parse(&text).map_err(|e| e.to_string())?;
+                property::same(Day10::part2(&v), naive_arrangements(&v))
+            });
+    }
+}
@@ -0,0 +1,201 @@
+use crate::automaton::{Dense, Rule};
+use crate::error::ParseError;
+use crate::grid::{consts::NEIGHBOURS_8, Grid, Location};
+use crate::solution::Solution;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Place { Floor, EmptySeat, OccupiedSeat }
+
+// The seats as an automaton whose live cells are the occupied seats, with
+// the number of each seat (in reading order) by position. The neighbours
+// of a seat are the seats adjacent to it, or with 'visible' the first
+// seat visible in each direction.
+fn seating(g: &Grid<Place>, visible: bool) -> (Dense, Grid<Option<usize>>) {
+    let mut seats = Vec::new();
+    let numbers = g.map(|p| (*p != Place::Floor).then(|| {
+        seats.push(*p == Place::OccupiedSeat);
+        seats.len() - 1
+    }));
+    let locations: Vec<Location> = g.entries_by_location()
+        .filter(|(_, p)| **p != Place::Floor)
+        .map(|(pos, _)| pos)
+        .collect();
+    let number = |pos: &Location| numbers.get_by_location(pos).copied().flatten();
+    let automaton = Dense::new(seats, |i| {
+        let l = &locations[i];
+        if visible {
+            NEIGHBOURS_8.iter()
+                .filter_map(|d| g.first_where(l, d, |p| *p != Place::Floor).and_then(|p| number(&p)))
+                .collect::<Vec<_>>()
+        } else {
+            g.neighbours(l, &NEIGHBOURS_8).filter_map(|p| number(&p)).collect()
+        }
+    });
+    (automaton, numbers)
+}
+
+// An empty seat becomes occupied if there are no occupied seats next to
+// it, and an occupied seat becomes empty if there are at least
+// 'threshold' occupied seats next to it.
+fn rule(threshold: usize) -> Rule {
+    Rule::new(&[0], &(0..threshold).collect::<Vec<_>>())
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    const TITLE: &'static str = "Seating System";
+
+    type Input = Grid<Place>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Grid<Place>, ParseError> {
+        Grid::parse(input, |c| match c {
+            b'.' => Ok(Place::Floor),
+            b'L' => Ok(Place::EmptySeat),
+            b'#' => Ok(Place::OccupiedSeat),
+            _ => Err("invalid place"),
+        })
+    }
+
+    fn part1(g: &Grid<Place>) -> usize {
+        // run the simulation using the adjacency rules
+        let (mut seats, _) = seating(g, false);
+        seats.run_until_stable(&rule(4));
+        seats.count_alive()
+    }
+
+    fn part2(g: &Grid<Place>) -> usize {
+        // run the simulation using the visibility rules
+        let (mut seats, _) = seating(g, true);
+        seats.run_until_stable(&rule(5));
+        seats.count_alive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::property::{self, Config};
+    use std::collections::HashSet;
+
+    // Simulates all the rounds on the whole layout, returning the number
+    // of occupied seats at the end, or None if the layout never settles.
+    fn naive_simulation(layout: &[Vec<u8>], threshold: usize, visible: bool) -> Option<usize> {
+        let (rows, cols) = (layout.len() as i64, layout[0].len() as i64);
+        let mut layout = layout.to_vec();
+        let mut seen = HashSet::new();
+        loop {
+            let mut next = layout.clone();
+            for y in 0..rows {
+                for x in 0..cols {
+                    let mut occupied = 0;
+                    for (dy, dx) in [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)] {
+                        let (mut ny, mut nx) = (y + dy, x + dx);
+                        while (0..rows).contains(&ny) && (0..cols).contains(&nx) {
+                            match layout[ny as usize][nx as usize] {
+                                b'#' => { occupied += 1; break; },
+                                b'L' => { break; },
+                                _ if !visible => { break; },
+                                _ => { ny += dy; nx += dx; },
+                            }
+                        }
+                    }
+                    let place = &mut next[y as usize][x as usize];
+                    if *place == b'L' && occupied == 0 { *place = b'#'; }
+                    else if *place == b'#' && occupied >= threshold { *place = b'L'; }
+                }
+            }
+            if next == layout {
+                return Some(layout.iter().flatten().filter(|c| **c == b'#').count());
+            }
+            if !seen.insert(next.clone()) {
+                return None;
+            }
+            layout = next;
+        }
+    }
+
+    #[test]
+    fn simulation_matches_naive() {
+        property::check(&Config::default().max_size(12),
+            |rng, size| {
+                let (rows, cols) = (1 + rng.below(size), 1 + rng.below(size));
+                (0..rows).map(|_| (0..cols).map(|_| *rng.choose(b"..LLLLLL##") as char).collect())
+                    .collect::<Vec<String>>()
+            },
+            |layout| {
+                // fewer rows, fewer columns or a seat less
+                let mut simpler: Vec<_> = property::shrink_vec(layout, |_| vec![]).into_iter()
+                    .filter(|l| !l.is_empty())
+                    .collect();
+                if layout[0].len() > 1 {
+                    simpler.push(layout.iter().map(|row| row[1..].to_string()).collect());
+                    simpler.push(layout.iter().map(|row| row[..row.len()-1].to_string()).collect());
+                }
+                for (y, row) in layout.iter().enumerate() {
+                    for (x, c) in row.char_indices().filter(|(_, c)| *c != '.') {
+                        let mut l = layout.clone();
+                        l[y].replace_range(x..x+1, if c == '#' { "L" } else { "." });
+                        simpler.push(l);
+                    }
+                }
+                simpler
+            },
+            |layout| {
+                let g = Day11::parse(&layout.join("\n")).map_err(|e| e.to_string())?;
+                let layout: Vec<_> = layout.iter().map(|row| row.as_bytes().to_vec()).collect();
+                // The fast simulation loops forever on layouts which never settle.
+                if let Some(naive) = naive_simulation(&layout, 4, false) {
+                    property::same(Day11::part1(&g), naive)?;
+                }
+                if let Some(naive) = naive_simulation(&layout, 5, true) {
+                    property::same(Day11::part2(&g), naive)?;
+                }
+                Ok(())
+            });
+    }
+
+    #[test]
+    fn example01() {
+        let g = Day11::parse("\
+.......#.
+...#.....
+.#.......
+.........
+..#L....#
+....#....
+.........
+#........
+...#.....").unwrap();
+        let (seats, numbers) = seating(&g, true);
+        assert_eq!(seats.live_neighbours(numbers.get(4, 3).unwrap().unwrap()), 8);
+    }
+
+    #[test]
+    fn example02() {
+        let g = Day11::parse("\
+.............
+.L.L.#.#.#.#.
+.............").unwrap();
+        let (seats, numbers) = seating(&g, true);
+        assert_eq!(seats.live_neighbours(numbers.get(1, 1).unwrap().unwrap()), 0);
+        assert_eq!(seats.live_neighbours(numbers.get(1, 3).unwrap().unwrap()), 1);
+    }
+
+    #[test]
+    fn example03() {
+        let g = Day11::parse("\
+.##.##.
+#.#.#.#
+##...##
+...L...
+##...##
+#.#.#.#
+.##.##.").unwrap();
+        let (seats, numbers) = seating(&g, true);
+        assert_eq!(seats.live_neighbours(numbers.get(3, 3).unwrap().unwrap()), 0);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::grid::{consts::*, Direction};
+use crate::error::ParseError;
+use crate::parser::{self, Scanner};
+use crate::solution::Solution;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    const TITLE: &'static str = "Rain Risk";
+
+    type Input = Vec<(char, i64)>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<(char, i64)>, ParseError> {
+        parser::lines(input, |s| {
+            let mut sc = Scanner::new(s);
+            let c = sc.char()?;
+            let n = sc.integer()?;
+            sc.end()?;
+            match (c, n) {
+                ('N' | 'S' | 'E' | 'W' | 'F', _) | ('L' | 'R', 90 | 180 | 270) => Ok((c, n)),
+                _ => Err(ParseError::new("unexpected instruction", s)),
+            }
+        })
+    }
+
+    fn part1(instructions: &Vec<(char, i64)>) -> i64 {
+        let mut pos1 = ORIGIN;
+        let mut dir = RIGHT;
+        for &(c, n) in instructions {
+            match (c, n) {
+                ('N', n) => { pos1 = pos1.go(&UP.times(n)); },
+                ('S', n) => { pos1 = pos1.go(&DOWN.times(n)); },
+                ('E', n) => { pos1 = pos1.go(&RIGHT.times(n)); },
+                ('W', n) => { pos1 = pos1.go(&LEFT.times(n)); },
+                ('L', 90) | ('R', 270) => { dir = dir.rotate_left(); },
+                ('L', 180) | ('R', 180) => { dir = dir.reverse(); },
+                ('L', 270) | ('R', 90) => { dir = dir.rotate_right(); },
+                ('F', n) => { pos1 = pos1.go(&dir.times(n)); },
+                _ => { panic!("unexpected instruction") },
+            }
+        }
+        pos1.distance(&ORIGIN)
+    }
+
+    fn part2(instructions: &Vec<(char, i64)>) -> i64 {
+        let mut pos2 = ORIGIN;
+        let mut way = Direction{ dx: 10, dy: -1 };
+        for &(c, n) in instructions {
+            match (c, n) {
+                ('N', n) => { way = way.add(&UP.times(n)); },
+                ('S', n) => { way = way.add(&DOWN.times(n)); },
+                ('E', n) => { way = way.add(&RIGHT.times(n)); },
+                ('W', n) => { way = way.add(&LEFT.times(n)); },
+                ('L', 90) | ('R', 270) => { way = way.rotate_left(); },
+                ('L', 180) | ('R', 180) => { way = way.reverse(); },
+                ('L', 270) | ('R', 90) => { way = way.rotate_right(); },
+                ('F', n) => { pos2 = pos2.go(&way.times(n)); },
+                _ => { panic!("unexpected instruction") },
+            }
+        }
+        pos2.distance(&ORIGIN)
+    }
+}
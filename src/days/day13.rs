@@ -0,0 +1,88 @@
+use crate::answers::Answer;
+use crate::error::{parse_number, ParseError};
+use crate::math;
+use crate::parser::{self, Scanner};
+use crate::solution::Solution;
+
+fn read_bus_list(s: &str) -> Result<(Vec<i64>, Vec<i64>), ParseError> {
+    let list: Vec<Option<i64>> = parser::list(s, ",", |bus| {
+        if bus == "x" {
+            return Ok(None);
+        }
+        let p = parse_number(bus)?;
+        if p <= 0 {
+            return Err(ParseError::new("invalid bus ID", bus));
+        }
+        Ok(Some(p))
+    })?;
+    // The bus at position m must leave m minutes after the timestamp.
+    let (minutes, busses): (Vec<i64>, Vec<i64>) = list.iter().enumerate()
+        .filter_map(|(m, bus)| bus.map(|p| (-(m as i64), p)))
+        .unzip();
+    if busses.is_empty() {
+        return Err(ParseError::new("no busses in service", s));
+    }
+    Ok((busses, minutes))
+}
+
+fn earliest_bus(busses: &[i64], timestamp: i64) -> (i64, i64) {
+    (0..).find_map(|wait| {
+        busses.iter().find(|bus| (timestamp + wait) % **bus == 0).map(|bus| (wait, *bus))
+    }).unwrap()
+}
+
+// The first timestamp fitting the bus list, if any.
+fn contest_timestamp(busses: &[i64], minutes: &[i64]) -> Option<u64> {
+    let congruences: Vec<(i64, u64)> = minutes.iter().zip(busses).map(|(&m, &bus)| (m, bus as u64)).collect();
+    math::crt(&congruences).map(|(timestamp, _)| timestamp)
+}
+
+pub struct Notes {
+    timestamp: i64,
+    busses: Vec<i64>,
+    minutes: Vec<i64>,
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    const TITLE: &'static str = "Shuttle Search";
+
+    type Input = Notes;
+    type Answer1 = i64;
+    type Answer2 = Answer;
+
+    fn parse(input: &str) -> Result<Notes, ParseError> {
+        let mut sc = Scanner::new(input);
+        let timestamp = sc.integer()?;
+        sc.literal("\n")?;
+        let (busses, minutes) = read_bus_list(sc.until("\n")?)?;
+        sc.end()?;
+        Ok(Notes{ timestamp, busses, minutes })
+    }
+
+    fn part1(notes: &Notes) -> i64 {
+        let (wait, bus) = earliest_bus(&notes.busses, notes.timestamp);
+        wait * bus
+    }
+
+    fn part2(notes: &Notes) -> Answer {
+        contest_timestamp(&notes.busses, &notes.minutes)
+            .map_or(Answer::Unsolved.with_detail("no timestamp fits the bus list"), Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contest() {
+        let notes = Day13::parse("939\n7,13,x,x,59,x,31,19\n").unwrap();
+        assert_eq!((Day13::part1(&notes), Day13::part2(&notes)), (295, Answer::Int(1068781)));
+        // bus 4 leaving at an even time and bus 6 at an odd one
+        let notes = Day13::parse("10\n4,6\n").unwrap();
+        assert_eq!(Day13::part2(&notes), Answer::Unsolved.with_detail("no timestamp fits the bus list"));
+    }
+}
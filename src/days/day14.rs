@@ -0,0 +1,219 @@
+use crate::error::{parse_number, ParseError};
+use crate::parser::{self, Scanner};
+use crate::solution::Solution;
+use crate::trace;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::fmt::Write;
+
+#[derive(Debug)]
+struct AddressRange {
+    addr: u64,
+    mask: u64, // follows the IP netmask convention: ones on non-floating bits
+}
+
+impl AddressRange {
+    fn intersect(&self, other: &AddressRange) -> Option<AddressRange> {
+        if (self.addr ^ other.addr) & (self.mask & other.mask) == 0 {
+            Some(AddressRange{ addr: self.addr | other.addr, mask: self.mask | other.mask })
+        } else {
+            None
+        }
+    }
+
+    fn len(&self) -> u64 {
+        1 << (36 - self.mask.count_ones())
+    }
+}
+
+impl Display for AddressRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let addr_str = format!("{:036b}", self.addr);
+        let mask_str = format!("{:036b}", self.mask);
+        for (a, m) in addr_str.chars().zip(mask_str.chars()) {
+            if m == '1' { f.write_char(a)?; }
+            else { f.write_char('_')?; }
+        }
+        Ok(())
+    }
+}
+
+pub enum Instruction {
+    Mask{ ones: u64, zeros: u64 },
+    Mem{ addr: u64, value: u64 },
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    const TITLE: &'static str = "Docking Data";
+
+    type Input = Vec<Instruction>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        parser::lines(input, |line| {
+            let mut sc = Scanner::new(line);
+            if sc.try_literal("mask = ") {
+                let right = sc.take_rest();
+                if right.len() != 36 {
+                    return Err(ParseError::new("the mask must have 36 bits", right));
+                }
+                let mut ones = 0;
+                let mut zeros = 0;
+                for (i, c) in right.char_indices() {
+                    match c {
+                        '0' => { zeros |= 1 << (35 - i) },
+                        '1' => { ones |= 1 << (35 - i) },
+                        'X' => {},
+                        _ => { return Err(ParseError::new("invalid mask bit", &right[i..])); },
+                    }
+                }
+                Ok(Instruction::Mask{ ones, zeros })
+            } else {
+                if !sc.try_literal("mem[") {
+                    return Err(sc.error("expected 'mask = ' or 'mem['"));
+                }
+                let left = sc.until("] = ")?;
+                let right = sc.take_rest();
+                let addr = parse_number::<u64>(left)?;
+                let value = parse_number::<u64>(right)?;
+                if addr >> 36 != 0 {
+                    return Err(ParseError::new("address out of range", left));
+                }
+                if value >> 36 != 0 {
+                    return Err(ParseError::new("value out of range", right));
+                }
+                Ok(Instruction::Mem{ addr, value })
+            }
+        })
+    }
+
+    fn part1(program: &Vec<Instruction>) -> i64 {
+        // memory model for v1 decoder
+        let mut memory = HashMap::<u64, i64>::new();
+
+        let mut mask_ones = 0;
+        let mut mask_zeros = 0;
+
+        for instruction in program {
+            match *instruction {
+                Instruction::Mask{ ones, zeros } => {
+                    mask_ones = ones;
+                    mask_zeros = zeros;
+                },
+                Instruction::Mem{ addr, value } => {
+                    // v1 decoding: store the masked value in memory
+                    let masked_value = ((value | mask_ones) & !mask_zeros) as i64;
+                    trace!(Trace, "mem[{}] = {}", addr, masked_value);
+                    memory.insert(addr, masked_value);
+                },
+            }
+        }
+
+        memory.values().sum()
+    }
+
+    fn part2(program: &Vec<Instruction>) -> i64 {
+        // memory model for v2 decoder: a list of address ranges
+        // with an associated value
+        let mut memory_ranges = Vec::<(AddressRange, i64)>::new();
+
+        let mut mask_ones = 0;
+        let mut mask_zeros = 0;
+
+        for instruction in program {
+            match *instruction {
+                Instruction::Mask{ ones, zeros } => {
+                    mask_ones = ones;
+                    mask_zeros = zeros;
+                },
+                Instruction::Mem{ addr, value } => {
+                    // v2 decoding: store the address range with the associated value
+                    let addrrange = AddressRange{
+                        addr: (addr | mask_ones) & (mask_zeros | mask_ones),
+                        mask: (mask_zeros | mask_ones),
+                    };
+
+                    // Before storing the address range, check if it overlaps with other ranges
+                    // and compensate by adding a range with the opposite of the value for the
+                    // overwritten part.
+                    let mut memory_overlaps: Vec<_> = memory_ranges
+                        .iter()
+                        .filter_map(|(a, v)| {
+                            addrrange.intersect(a).map(|o| (o, -v))
+                        })
+                        .inspect(|(a, v)| trace!(Trace, "del: mem[{}] -> {}", a, v))
+                        .collect();
+                    memory_ranges.append(&mut memory_overlaps);
+
+                    trace!(Trace, "add: mem[{}] -> {}", addrrange, value as i64);
+                    memory_ranges.push((addrrange, value as i64));
+                },
+            }
+        }
+
+        memory_ranges.iter().map(|(a, v)| a.len() as i64 * v).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::property::{self, Config};
+
+    // Writes to every floating address one by one.
+    fn naive_part2(program: &[Instruction]) -> i64 {
+        let mut memory = HashMap::<u64, u64>::new();
+        let mut mask_ones = 0;
+        let mut floating = (1 << 36) - 1;
+        for instruction in program {
+            match *instruction {
+                Instruction::Mask{ ones, zeros } => {
+                    mask_ones = ones;
+                    floating = !(ones | zeros) & ((1 << 36) - 1);
+                },
+                Instruction::Mem{ addr, value } => {
+                    let base = (addr | mask_ones) & !floating;
+                    let mut bits = floating;
+                    loop {
+                        memory.insert(base | bits, value);
+                        if bits == 0 { break; }
+                        bits = (bits - 1) & floating;
+                    }
+                },
+            }
+        }
+        memory.values().map(|v| *v as i64).sum()
+    }
+
+    #[test]
+    fn address_ranges_match_naive() {
+        // Few floating bits, all in the low byte with the addresses, so
+        // that the ranges overlap often.
+        property::check(&Config::default(),
+            |rng, size| {
+                (0..rng.below(size) + 1).map(|i| {
+                    if i == 0 || rng.chance(0.3) {
+                        let k = rng.below(6);
+                        let floating = rng.sample(8, k);
+                        let mask: String = (0..36).map(|bit| {
+                            if floating.contains(&(35 - bit)) { 'X' }
+                            else if rng.chance(0.5) { '1' }
+                            else { '0' }
+                        }).collect();
+                        format!("mask = {}", mask)
+                    } else {
+                        format!("mem[{}] = {}", rng.below(256), rng.below(1000))
+                    }
+                }).collect::<Vec<_>>()
+            },
+            |lines| property::shrink_vec(lines, |_| vec![]),
+            |lines| {
+                let program = Day14::parse(&lines.join("\n")).map_err(|e| e.to_string())?;
+                property::same(Day14::part2(&program), naive_part2(&program))
+            });
+    }
+}
@@ -0,0 +1,154 @@
+use crate::error::{parse_number, ParseError};
+use crate::params::{Param, Params};
+use crate::parser;
+use crate::solution::Solution;
+use crate::trace;
+use std::collections::HashMap;
+
+// The last turn when each number was seen (or 0 if never seen), as a
+// vector for the numbers less than the number of turns (which include all
+// the numbers generated, since they are ages), and a map for the larger
+// starting numbers. Turns are limited to 32 bits for efficiency.
+struct LastSeen {
+    dense: Vec<u32>,
+    sparse: HashMap<usize, u32>,
+}
+
+impl LastSeen {
+    fn get(&self, n: usize) -> u32 {
+        match self.dense.get(n) {
+            Some(t) => *t,
+            None => self.sparse.get(&n).copied().unwrap_or(0),
+        }
+    }
+
+    fn set(&mut self, n: usize, turn: usize) {
+        match self.dense.get_mut(n) {
+            Some(t) => *t = turn as u32,
+            None => { self.sparse.insert(n, turn as u32); },
+        }
+    }
+}
+
+fn play(start: &[usize], turns: usize) -> usize {
+    let mut last_seen = LastSeen{ dense: vec![0; turns], sparse: HashMap::new() };
+
+    let mut turn = 1;
+    let mut spoken = start[0];
+
+    while turn < start.len().min(turns) {
+        last_seen.set(spoken, turn);
+        turn += 1;
+        spoken = start[turn-1];
+    }
+
+    if turn < turns {
+        let age = match last_seen.get(spoken) { 0 => 0, t => turn - t as usize};
+        last_seen.set(spoken, turn);
+        turn += 1;
+        spoken = age;
+    }
+
+    // From now on, the numbers spoken are ages, so less than the turn.
+    let last_seen = &mut last_seen.dense;
+    while turn < turns {
+        let age = match last_seen[spoken] { 0 => 0, t => turn - t as usize};
+        last_seen[spoken] = turn as u32;
+        turn += 1;
+        spoken = age;
+        trace!(Trace, "turn {}: spoken {}", turn, spoken);
+    }
+
+    spoken
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    const TITLE: &'static str = "Rambunctious Recitation";
+
+    const PARAMS: &'static [Param] = &[
+        Param{ name: "turns1", default: "2020", help: "the number of turns of part 1" },
+        Param{ name: "turns2", default: "30000000", help: "the number of turns of part 2" },
+    ];
+
+    // The starting numbers and the number of turns of the two parts.
+    type Input = (Vec<usize>, [usize; 2]);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<(Vec<usize>, [usize; 2]), ParseError> {
+        Self::parse_with(input, &Params::new(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<(Vec<usize>, [usize; 2]), ParseError> {
+        let start = parser::list(input.trim_end(), ",", parse_number)?;
+        let mut turns = [0; 2];
+        for (t, name) in turns.iter_mut().zip(["turns1", "turns2"]) {
+            *t = params.get(name)?;
+            if *t == 0 || *t > u32::MAX as usize {
+                return Err(ParseError::new(format!("{} must be between 1 and {}", name, u32::MAX), params.get_str(name)));
+            }
+        }
+        Ok((start, turns))
+    }
+
+    fn part1((start, turns): &(Vec<usize>, [usize; 2])) -> usize {
+        play(start, turns[0])
+    }
+
+    fn part2((start, turns): &(Vec<usize>, [usize; 2])) -> usize {
+        play(start, turns[1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example01() {
+        assert_eq!(play(&[0, 3, 6], 4), 0);
+        assert_eq!(play(&[0, 3, 6], 5), 3);
+        assert_eq!(play(&[0, 3, 6], 6), 3);
+        assert_eq!(play(&[0, 3, 6], 7), 1);
+        assert_eq!(play(&[0, 3, 6], 8), 0);
+        assert_eq!(play(&[0, 3, 6], 9), 4);
+        assert_eq!(play(&[0, 3, 6], 10), 0);
+    }
+
+
+    #[test]
+    #[ignore]
+    fn example03() {
+        assert_eq!(play(&[0, 3, 6], 30000000), 175594);
+        assert_eq!(play(&[1, 3, 2], 30000000), 2578);
+        assert_eq!(play(&[2, 1, 3], 30000000), 3544142);
+        assert_eq!(play(&[1, 2, 3], 30000000), 261214);
+        assert_eq!(play(&[2, 3, 1], 30000000), 6895259);
+        assert_eq!(play(&[3, 2, 1], 30000000), 18);
+        assert_eq!(play(&[3, 1, 2], 30000000), 362);
+    }
+
+    #[test]
+    fn parameters() {
+        let mut params = Params::new(Day15::PARAMS);
+        params.set("turns1", "10").unwrap();
+        let input = Day15::parse_with("0,3,6", &params).unwrap();
+        assert_eq!(Day15::part1(&input), 0);
+        params.set("turns1", "2").unwrap();
+        assert_eq!(Day15::part1(&Day15::parse_with("0,3,6", &params).unwrap()), 3);
+        params.set("turns2", "0").unwrap();
+        assert!(Day15::parse_with("0,3,6", &params).is_err());
+    }
+
+    #[test]
+    fn large_starting_numbers() {
+        assert_eq!(play(&[usize::MAX, 4000000000], 2), 4000000000);
+        assert_eq!(play(&[usize::MAX, 4000000000], 3), 0);
+        // a large number repeated at the start, 2 turns apart
+        assert_eq!(play(&[4000000000, 1, 4000000000], 4), 2);
+        assert_eq!(play(&[4000000000, 1, 4000000000], 6), 0);
+    }
+}
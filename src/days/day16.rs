@@ -0,0 +1,188 @@
+use crate::error::{parse_number, ParseError};
+use crate::parser::{self, Scanner};
+use crate::solution::Solution;
+use crate::trace;
+
+#[derive(Clone, Debug)]
+struct TicketField {
+    name: String,
+    ranges: Vec<(u64, u64)>,
+    position: Option<usize>,
+}
+
+impl TicketField {
+    fn create_from(s: &str) -> Result<TicketField, ParseError> {
+        let (name, ranges) = parser::key_value(s, ": ")?;
+        let name = name.to_string();
+        let ranges = parser::list(ranges, " or ", |range| {
+            let mut sc = Scanner::new(range);
+            let min = sc.integer()?;
+            sc.literal("-")?;
+            let max = sc.integer()?;
+            sc.end()?;
+            Ok((min, max))
+        })?;
+
+        Ok(TicketField { name, ranges, position: None /* unknown */ })
+    }
+
+    fn allows(&self, value: u64) -> bool {
+        self.ranges.iter().any(|(min, max)| value >= *min && value <= *max)
+    }
+}
+
+fn read_ticket(s: &str, n_fields: usize) -> Result<Vec<u64>, ParseError> {
+    let ticket: Vec<_> = parser::list(s, ",", parse_number)?;
+    if ticket.len() != n_fields {
+        return Err(ParseError::new(format!("expected {} values", n_fields), s));
+    }
+    Ok(ticket)
+}
+
+// The fields, my ticket and the nearby tickets.
+type Sections = (Vec<TicketField>, Vec<u64>, Vec<Vec<u64>>);
+
+fn read_input(input: &str) -> Result<Sections, ParseError> {
+    let (fields_part, my_ticket_part, nearby_part) = match parser::sections(input).collect::<Vec<_>>()[..] {
+        [fields, mine, nearby] => (fields, mine, nearby),
+        _ => { return Err(ParseError::new("expected the fields, your ticket and the nearby tickets", input)); },
+    };
+
+    let fields: Vec<_> = parser::lines(fields_part, TicketField::create_from)?;
+
+    let mut sc = Scanner::new(my_ticket_part);
+    sc.literal("your ticket:\n")?;
+    let my_ticket = read_ticket(sc.rest(), fields.len())?;
+
+    let mut sc = Scanner::new(nearby_part);
+    sc.literal("nearby tickets:\n")?;
+    let nearby_tickets = parser::lines(sc.rest(), |line| read_ticket(line, fields.len()))?;
+
+    Ok((fields, my_ticket, nearby_tickets))
+}
+
+fn filter_tickets(fields: &[TicketField], tickets: &[Vec<u64>]) -> (Vec<Vec<u64>>, u64) {
+    let mut valid_tickets = Vec::new();
+    let mut error_rate = 0;
+
+    for ticket in tickets {
+        let mut valid = true;
+        for v in ticket {
+            if fields.iter().all(|f| !f.allows(*v)) {
+                valid = false;
+                error_rate += *v;
+            }
+        }
+        if valid {
+            valid_tickets.push(ticket.clone());
+        }
+    }
+
+    (valid_tickets, error_rate)
+}
+
+fn assign_fields(fields: &mut [TicketField], tickets: &[Vec<u64>]) {
+    // Collect the possible positions for each field.
+    let mut possible_positions = Vec::new();
+    for field in fields.iter() {
+        let mut possible_positions_f = Vec::new();
+        for i in 0..fields.len() {
+            if tickets.iter().all(|t| field.allows(t[i])) {
+                possible_positions_f.push(i);
+            }
+        }
+
+        trace!(Debug, "field {} can be in position: {:?}", field.name, possible_positions_f);
+        possible_positions.push(possible_positions_f);
+    }
+
+    // Assign a position to each field
+    while let Some(f) = possible_positions.iter().position(|list| list.len() == 1) {
+        // field f can only be in one position
+        let assigned_position = possible_positions[f][0];
+
+        trace!(Debug, "field {} is in position {}", fields[f].name, assigned_position);
+        fields[f].position = Some(assigned_position);
+
+        // remove this position from all lists
+        for list in &mut possible_positions {
+            if let Some(i) = list.iter().position(|i| *i == assigned_position) {
+                list.remove(i);
+            }
+        }
+    }
+
+    if !possible_positions.iter().all(|list| list.is_empty()) {
+        panic!("the problem does not have a unique solution");
+    }
+
+    // Sort fields by position.
+    fields.sort_by_key(|f| f.position.unwrap());
+}
+
+pub struct Notes {
+    fields: Vec<TicketField>,
+    my_ticket: Vec<u64>,
+    nearby_tickets: Vec<Vec<u64>>,
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+    const TITLE: &'static str = "Ticket Translation";
+
+    type Input = Notes;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Notes, ParseError> {
+        let (fields, my_ticket, nearby_tickets) = read_input(input)?;
+        Ok(Notes{ fields, my_ticket, nearby_tickets })
+    }
+
+    fn part1(notes: &Notes) -> u64 {
+        let (_, error_rate) = filter_tickets(&notes.fields, &notes.nearby_tickets);
+        error_rate
+    }
+
+    fn part2(notes: &Notes) -> u64 {
+        let (valid_tickets, _) = filter_tickets(&notes.fields, &notes.nearby_tickets);
+
+        let mut fields = notes.fields.clone();
+        assign_fields(&mut fields, &valid_tickets);
+
+        fields
+            .iter()
+            .filter(|f| f.name.starts_with("departure"))
+            .map(|f| notes.my_ticket[f.position.unwrap()])
+            .product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example02() {
+        let (mut fields, _, tickets) = read_input("\
+class: 0-1 or 4-19
+row: 0-5 or 8-19
+seat: 0-13 or 16-19
+
+your ticket:
+11,12,13
+
+nearby tickets:
+3,9,18
+15,1,5
+5,14,9").unwrap();
+        assign_fields(&mut fields, &tickets);
+
+        println!("{:?}", fields);
+        assert_eq!(fields[0].name, "row");
+        assert_eq!(fields[1].name, "class");
+        assert_eq!(fields[2].name, "seat");
+    }
+}
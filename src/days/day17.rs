@@ -0,0 +1,485 @@
+use crate::automaton::{Sparse, LIFE};
+use crate::error::ParseError;
+use crate::grid::SimpleGrid;
+use crate::params::{Param, Params};
+use crate::solution::Solution;
+use std::fmt::Display;
+
+// This struct stores the positions of the active cubes.
+#[derive(Clone)]
+pub struct ActiveCubes(Sparse<(i64, i64, i64, i64)>);
+
+impl ActiveCubes {
+    fn create_from(s: &str) -> Result<ActiveCubes, ParseError> {
+        if let Some(p) = s.find(|c| !".#\n".contains(c)) {
+            return Err(ParseError::new("expected '.' or '#'", &s[p..]));
+        }
+        let g = SimpleGrid::create_from(s)?;
+        let cubes = g.entries()
+            .filter(|(_, c)| **c == b'#')
+            .map(|((row, col), _)| (col as i64, row as i64, 0, 0));
+        Ok(ActiveCubes(Sparse::new(cubes)))
+    }
+
+    // apply the the rules using a neighborhood radius of (xr, yr, zr, wr)
+    fn advance( &mut self, xr: i64, yr: i64, zr: i64, wr: i64 ) {
+        let mut offsets = Vec::new();
+        for dw in -wr..=wr {
+            for dz in -zr..=zr {
+                for dy in -yr..=yr {
+                    for dx in -xr..=xr {
+                        if (dx, dy, dz, dw) != (0, 0, 0, 0) {
+                            offsets.push((dx, dy, dz, dw));
+                        }
+                    }
+                }
+            }
+        }
+
+        self.0.step(&LIFE, |(x, y, z, w)| {
+            offsets.iter().map(move |(dx, dy, dz, dw)| (x+dx, y+dy, z+dz, w+dw))
+        });
+    }
+}
+
+impl Display for ActiveCubes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let x_min = *self.0.live().iter().map(|(x, _, _, _)| x).min().unwrap();
+        let x_max = *self.0.live().iter().map(|(x, _, _, _)| x).max().unwrap();
+        let y_min = *self.0.live().iter().map(|(_, y, _, _)| y).min().unwrap();
+        let y_max = *self.0.live().iter().map(|(_, y, _, _)| y).max().unwrap();
+        let z_min = *self.0.live().iter().map(|(_, _, z, _)| z).min().unwrap();
+        let z_max = *self.0.live().iter().map(|(_, _, z, _)| z).max().unwrap();
+        let w_min = *self.0.live().iter().map(|(_, _, _, w)| w).min().unwrap();
+        let w_max = *self.0.live().iter().map(|(_, _, _, w)| w).max().unwrap();
+
+        for w in w_min..=w_max {
+            for z in z_min..=z_max {
+                if w_min == 0 && w_max == 0 {
+                    writeln!(f, "z={}", z)?;
+                } else {
+                    writeln!(f, "z={}, w={}", z, w)?;
+                }
+                for y in y_min..=y_max {
+                    for x in x_min..=x_max {
+                        if self.0.live().contains(&(x, y, z, w)) {
+                            write!(f, "#")?;
+                        } else {
+                            write!(f, ".")?;
+                        }
+                    }
+                    writeln!(f)?;
+                }
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+    const TITLE: &'static str = "Conway Cubes";
+
+    const PARAMS: &'static [Param] = &[
+        Param{ name: "cycles", default: "6", help: "the number of cycles of the boot process" },
+    ];
+
+    // The initial cubes and the number of cycles.
+    type Input = (ActiveCubes, usize);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<(ActiveCubes, usize), ParseError> {
+        Self::parse_with(input, &Params::new(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<(ActiveCubes, usize), ParseError> {
+        Ok((ActiveCubes::create_from(input)?, params.get("cycles")?))
+    }
+
+    fn part1((cubes, cycles): &(ActiveCubes, usize)) -> usize {
+        let mut cubes3d = cubes.clone();
+        for _ in 0..*cycles {
+            cubes3d.advance(1, 1, 1, 0);
+        }
+        cubes3d.0.len()
+    }
+
+    fn part2((cubes, cycles): &(ActiveCubes, usize)) -> usize {
+        let mut cubes4d = cubes.clone();
+        for _ in 0..*cycles {
+            cubes4d.advance(1, 1, 1, 1);
+        }
+        cubes4d.0.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example01() {
+        let mut cubes = ActiveCubes::create_from(
+            "\
+.#.
+..#
+###").unwrap();
+        cubes.advance(1, 1, 1, 0);
+        println!("{}", cubes);
+        assert_eq!(
+            cubes.to_string().trim(),
+            "\
+z=-1
+#..
+..#
+.#.
+
+z=0
+#.#
+.##
+.#.
+
+z=1
+#..
+..#
+.#.");
+        cubes.advance(1, 1, 1, 0);
+        println!("{}", cubes);
+        assert_eq!(
+            cubes.to_string().trim(),
+            "\
+z=-2
+.....
+.....
+..#..
+.....
+.....
+
+z=-1
+..#..
+.#..#
+....#
+.#...
+.....
+
+z=0
+##...
+##...
+#....
+....#
+.###.
+
+z=1
+..#..
+.#..#
+....#
+.#...
+.....
+
+z=2
+.....
+.....
+..#..
+.....
+.....");
+        cubes.advance(1, 1, 1, 0);
+        println!("{}", cubes);
+        assert_eq!(
+            cubes.to_string().trim(),
+            "\
+z=-2
+.......
+.......
+..##...
+..###..
+.......
+.......
+.......
+
+z=-1
+..#....
+...#...
+#......
+.....##
+.#...#.
+..#.#..
+...#...
+
+z=0
+...#...
+.......
+#......
+.......
+.....##
+.##.#..
+...#...
+
+z=1
+..#....
+...#...
+#......
+.....##
+.#...#.
+..#.#..
+...#...
+
+z=2
+.......
+.......
+..##...
+..###..
+.......
+.......
+.......");
+        cubes.advance(1, 1, 1, 0);
+        cubes.advance(1, 1, 1, 0);
+        cubes.advance(1, 1, 1, 0);
+        assert_eq!(cubes.0.len(), 112);
+    }
+
+    #[test]
+    fn example02() {
+        let mut cubes = ActiveCubes::create_from(
+            "\
+.#.
+..#
+###").unwrap();
+        cubes.advance(1, 1, 1, 1);
+        println!("{}", cubes);
+        assert_eq!(
+            cubes.to_string().trim(),
+            "\
+z=-1, w=-1
+#..
+..#
+.#.
+
+z=0, w=-1
+#..
+..#
+.#.
+
+z=1, w=-1
+#..
+..#
+.#.
+
+z=-1, w=0
+#..
+..#
+.#.
+
+z=0, w=0
+#.#
+.##
+.#.
+
+z=1, w=0
+#..
+..#
+.#.
+
+z=-1, w=1
+#..
+..#
+.#.
+
+z=0, w=1
+#..
+..#
+.#.
+
+z=1, w=1
+#..
+..#
+.#.");
+        cubes.advance(1, 1, 1, 1);
+        println!("{}", cubes);
+        assert_eq!(
+            cubes.to_string().trim(),
+            "\
+z=-2, w=-2
+.....
+.....
+..#..
+.....
+.....
+
+z=-1, w=-2
+.....
+.....
+.....
+.....
+.....
+
+z=0, w=-2
+###..
+##.##
+#...#
+.#..#
+.###.
+
+z=1, w=-2
+.....
+.....
+.....
+.....
+.....
+
+z=2, w=-2
+.....
+.....
+..#..
+.....
+.....
+
+z=-2, w=-1
+.....
+.....
+.....
+.....
+.....
+
+z=-1, w=-1
+.....
+.....
+.....
+.....
+.....
+
+z=0, w=-1
+.....
+.....
+.....
+.....
+.....
+
+z=1, w=-1
+.....
+.....
+.....
+.....
+.....
+
+z=2, w=-1
+.....
+.....
+.....
+.....
+.....
+
+z=-2, w=0
+###..
+##.##
+#...#
+.#..#
+.###.
+
+z=-1, w=0
+.....
+.....
+.....
+.....
+.....
+
+z=0, w=0
+.....
+.....
+.....
+.....
+.....
+
+z=1, w=0
+.....
+.....
+.....
+.....
+.....
+
+z=2, w=0
+###..
+##.##
+#...#
+.#..#
+.###.
+
+z=-2, w=1
+.....
+.....
+.....
+.....
+.....
+
+z=-1, w=1
+.....
+.....
+.....
+.....
+.....
+
+z=0, w=1
+.....
+.....
+.....
+.....
+.....
+
+z=1, w=1
+.....
+.....
+.....
+.....
+.....
+
+z=2, w=1
+.....
+.....
+.....
+.....
+.....
+
+z=-2, w=2
+.....
+.....
+..#..
+.....
+.....
+
+z=-1, w=2
+.....
+.....
+.....
+.....
+.....
+
+z=0, w=2
+###..
+##.##
+#...#
+.#..#
+.###.
+
+z=1, w=2
+.....
+.....
+.....
+.....
+.....
+
+z=2, w=2
+.....
+.....
+..#..
+.....
+.....");
+        cubes.advance(1, 1, 1, 1);
+        cubes.advance(1, 1, 1, 1);
+        cubes.advance(1, 1, 1, 1);
+        cubes.advance(1, 1, 1, 1);
+        assert_eq!(cubes.0.len(), 848);
+    }
+}
@@ -0,0 +1,192 @@
+use crate::answers::Answer;
+use crate::error::ParseError;
+use crate::parser;
+use crate::solution::Solution;
+use crate::trace;
+
+// Checks the syntax (operands, which are digits or expressions in
+// parentheses, separated by operators) and returns the tokens.
+fn tokenize(s: &str) -> Result<Vec<char>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut expect_operand = true;
+    let mut depth = 0usize;
+    for (p, c) in s.char_indices().filter(|(_, c)| !c.is_ascii_whitespace()) {
+        match (c, expect_operand) {
+            ('0'..='9', true) => expect_operand = false,
+            ('(', true) => depth += 1,
+            (')', false) if depth > 0 => depth -= 1,
+            ('+' | '*', false) => expect_operand = true,
+            (')' | '+' | '*', true) => return Err(ParseError::new("expected a digit or '('", &s[p..])),
+            ('0'..='9' | '(' | ')', false) => return Err(ParseError::new("expected an operator or ')'", &s[p..])),
+            _ => return Err(ParseError::new("unexpected character", &s[p..])),
+        }
+        tokens.push(c);
+    }
+    if expect_operand || depth > 0 {
+        return Err(ParseError::new("incomplete expression", s));
+    }
+    Ok(tokens)
+}
+
+// Look for a top-level operator among the ones in 'ops' (left to right)
+// and return the expression decomposed as (left, op, right).
+fn split_on_op<'a>(expr: &'a [char], ops: &[char]) -> Option<(&'a [char], char, &'a [char])> {
+    let mut p = expr.len() - 1;
+    let mut nested = 0;
+    while p > 0 {
+        if nested == 0 && ops.contains(&expr[p]) {
+            return Some((&expr[0..p], expr[p], &expr[p+1..]));
+        }
+        if expr[p] == '(' { nested -= 1; };
+        if expr[p] == ')' { nested += 1; };
+        p -= 1;
+    }
+    None
+}
+
+fn evaluate(expr: &[char]) -> Option<u64> {
+    trace!(Trace, "evaluating: {}", expr.iter().collect::<String>());
+    match expr.len() {
+        0 => { None },
+        1 => { expr[0].to_digit(10).map(|n| n as u64) },
+        _ => {
+            // Look for a top-level multiplication or addition.
+            // Evaluate recursively the left and right parts and aggregate the results.
+            match split_on_op(expr, &['*', '+']) {
+                Some((left, '+', right)) => { evaluate(left)?.checked_add(evaluate(right)?) },
+                Some((left, '*', right)) => { evaluate(left)?.checked_mul(evaluate(right)?) },
+                _ => {
+                    // No top-level operation, evaluate the expression in parentheses.
+                    if expr[0] == '(' && expr[expr.len()-1] == ')' {
+                        evaluate(&expr[1..expr.len()-1])
+                    } else {
+                        None
+                    }
+                },
+            }
+        },
+    }
+}
+
+fn evaluate_adv(expr: &[char]) -> Option<u64> {
+    trace!(Trace, "evaluating: {}", expr.iter().collect::<String>());
+    match expr.len() {
+        0 => { None },
+        1 => { expr[0].to_digit(10).map(|n| n as u64) },
+        _ => {
+            // Look for a top-level multiplication, then for an addition.
+            // Evaluate recursively the left and right parts and aggregate the results.
+            match split_on_op(expr, &['*']).or(split_on_op(expr, &['+'])) {
+                Some((left, '+', right)) => { evaluate_adv(left)?.checked_add(evaluate_adv(right)?) },
+                Some((left, '*', right)) => { evaluate_adv(left)?.checked_mul(evaluate_adv(right)?) },
+                _ => {
+                    // No top-level operation, evaluate the expression in parentheses.
+                    if expr[0] == '(' && expr[expr.len()-1] == ')' {
+                        evaluate_adv(&expr[1..expr.len()-1])
+                    } else {
+                        None
+                    }
+                },
+            }
+        },
+    }
+}
+
+// The sum of the values of the expressions, unsolved if it overflows (the
+// syntax being checked, the evaluations can only fail on overflows).
+fn sum(expressions: &[Vec<char>], evaluate: fn(&[char]) -> Option<u64>) -> Answer {
+    expressions.iter()
+        .try_fold(0u64, |sum, expr| sum.checked_add(evaluate(expr)?))
+        .map_or(Answer::Unsolved.with_detail("value out of range"), Answer::from)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+    const TITLE: &'static str = "Operation Order";
+
+    type Input = Vec<Vec<char>>;
+    type Answer1 = Answer;
+    type Answer2 = Answer;
+
+    fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+        parser::lines(input, tokenize)
+    }
+
+    fn part1(expressions: &Vec<Vec<char>>) -> Answer {
+        sum(expressions, evaluate)
+    }
+
+    fn part2(expressions: &Vec<Vec<char>>) -> Answer {
+        sum(expressions, evaluate_adv)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::property::{self, Config};
+    use crate::rng::Rng;
+
+    // A random expression with about 'budget' digits from 1 to 3 (at most
+    // twice as many), so that its value fits in a u64.
+    fn expression(rng: &mut Rng, budget: &mut usize) -> String {
+        let mut s = operand(rng, budget);
+        while *budget > 0 && rng.chance(0.7) {
+            s += if rng.chance(0.5) { " + " } else { " * " };
+            s += &operand(rng, budget);
+        }
+        s
+    }
+
+    fn operand(rng: &mut Rng, budget: &mut usize) -> String {
+        if *budget > 1 && rng.chance(0.3) {
+            format!("({})", expression(rng, budget))
+        } else {
+            *budget = budget.saturating_sub(1);
+            rng.range(1..=3).to_string()
+        }
+    }
+
+    #[test]
+    fn syntax() {
+        let input = Day18::parse("1 + (2 * 3) + (4 * (5 + 6))\n((2))").unwrap();
+        assert_eq!((Day18::part1(&input), Day18::part2(&input)), (Answer::Int(53), Answer::Int(53)));
+        let error = |s| Day18::parse(s).unwrap_err().to_string();
+        assert_eq!(error("1 + x"), "unexpected character at 'x'");
+        assert_eq!(error("1 + * 2"), "expected a digit or '(' at '* 2'");
+        assert_eq!(error("12"), "expected an operator or ')' at '2'");
+        assert_eq!(error("(1 + 2))"), "expected an operator or ')' at ')'");
+        assert_eq!(error("()"), "expected a digit or '(' at ')'");
+        assert_eq!(error("(1 + 2"), "incomplete expression at '(1 + 2'");
+        assert_eq!(error("1 +"), "incomplete expression at '1 +'");
+    }
+
+    #[test]
+    fn valid_expressions_evaluate() {
+        // only overflows can make the evaluation of a valid expression fail
+        property::check(&Config::default(),
+            |rng, size| expression(rng, &mut size.clone()),
+            |_| vec![],
+            |expr| {
+                let input = Day18::parse(expr).map_err(|e| e.to_string())?;
+                if Day18::part1(&input).is_solved() && Day18::part2(&input).is_solved() {
+                    Ok(())
+                } else {
+                    Err("unsolved".to_string())
+                }
+            });
+    }
+
+    #[test]
+    fn overflow() {
+        // 9^20 fits in a u64, 9^21 does not
+        let power = |n| vec!["9"; n].join(" * ");
+        let input = Day18::parse(&power(20)).unwrap();
+        assert_eq!(Day18::part1(&input), Answer::from(9u64.pow(20)));
+        assert!(!Day18::part2(&Day18::parse(&power(21)).unwrap()).is_solved());
+        let sum = format!("{}\n{}", power(20), power(20));
+        assert!(!Day18::part1(&Day18::parse(&sum).unwrap()).is_solved());
+    }
+}
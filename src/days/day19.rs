@@ -0,0 +1,157 @@
+use crate::error::{parse_number, ParseError};
+use crate::parser;
+use crate::solution::Solution;
+use crate::trace;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug)]
+enum Rule {
+    Literal(char),
+    And(Vec<usize>),
+    Or(Vec<Rule>),
+}
+
+impl Rule {
+    fn create_from(r: &[&str]) -> Result<Rule, ParseError> {
+        if r.len() == 1 && r[0].starts_with('"') {
+            let mut chars = r[0].chars();
+            return match (chars.next(), chars.next(), chars.next(), chars.next()) {
+                (Some('"'), Some(c), Some('"'), None) => Ok(Rule::Literal(c)),
+                _ => Err(ParseError::new("expected a single character in quotes", r[0])),
+            };
+        }
+        if let Some(i) = r.iter().position(|x| *x == "|") {
+            return Ok(Rule::Or(vec![Rule::create_from(&r[0..i])?, Rule::create_from(&r[i+1..])?]));
+        }
+        Ok(Rule::And(r.iter().map(|x| parse_number(x)).collect::<Result<_, _>>()?))
+    }
+
+    // The ids of the other rules this rule refers to.
+    fn references(&self) -> Vec<usize> {
+        match self {
+            Rule::Literal(_) => vec![],
+            Rule::And(list) => list.clone(),
+            Rule::Or(list) => list.iter().flat_map(Rule::references).collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RuleSet {
+    rules: HashMap<usize, Rule>,
+}
+
+impl RuleSet {
+    fn create_from(s: &str) -> Result<RuleSet, ParseError> {
+        let lines: Vec<(&str, usize, Rule)> = parser::lines(s, |line| {
+            let (id, rule) = parser::key_value(line, ": ")?;
+            let rule = rule.split_whitespace().collect::<Vec<_>>();
+            Ok((line, parse_number(id)?, Rule::create_from(&rule)?))
+        })?;
+        let rules: HashMap<_, _> = lines.iter().map(|(_, id, rule)| (*id, rule.clone())).collect();
+        for (line, _, rule) in &lines {
+            if rule.references().iter().any(|r| !rules.contains_key(r)) {
+                return Err(ParseError::new("reference to an undefined rule", line));
+            }
+        }
+        if !rules.contains_key(&0) {
+            return Err(ParseError::new("missing rule 0", s));
+        }
+        Ok(RuleSet{ rules })
+    }
+
+    // Matches a rule in all possible ways on the initial part of a message,
+    // returning the possible leftover messages after positive matches.
+    fn matches<'a>(&self, rule: &Rule, msg: &'a str) -> Vec<&'a str> {
+        trace!(Trace, "matching rule {:?} on {}", rule, msg);
+        match rule {
+            Rule::Literal(c) => {
+                match msg.strip_prefix(*c) {
+                    Some(msg_tail) => { vec![msg_tail] },
+                    _ => { vec![] },
+                }
+            }
+            Rule::And(list) => {
+                match list.split_first() {
+                    Some((i, tail)) => {
+                        self.matches(&self.rules[i], msg)
+                            .into_iter()
+                            .flat_map(|msg_tail| self.matches(&Rule::And(tail.to_vec()), msg_tail))
+                            .collect()
+                    },
+                    None => { vec![msg] },
+                }
+            }
+            Rule::Or(list) => {
+                list.iter().flat_map(|r| self.matches(r, msg)).collect()
+            }
+        }
+    }
+
+    fn fully_matches(&self, rule: usize, msg: &str) -> bool {
+        self.matches(&self.rules[&rule], msg).iter().find(|r| r.is_empty()).is_some()
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+    const TITLE: &'static str = "Monster Messages";
+
+    type Input = (RuleSet, Vec<String>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<(RuleSet, Vec<String>), ParseError> {
+        let (rules, messages) = match parser::sections(input).collect::<Vec<_>>()[..] {
+            [rules, messages] => (rules, messages),
+            _ => { return Err(ParseError::new("expected the rules and the messages", input)); },
+        };
+        Ok((RuleSet::create_from(rules)?, messages.lines().map(String::from).collect()))
+    }
+
+    fn part1((ruleset, messages): &(RuleSet, Vec<String>)) -> usize {
+        messages.iter()
+            .filter(|msg| {
+                let result = ruleset.fully_matches(0, msg);
+                trace!(Debug, "tested original ruleset on {} -> {}", msg, result);
+                result
+            })
+            .count()
+    }
+
+    fn part2((ruleset, messages): &(RuleSet, Vec<String>)) -> usize {
+        let mut ruleset_mod = ruleset.clone();
+        ruleset_mod.rules.insert(8, Rule::create_from(&["42", "|", "42", "8"]).unwrap());
+        ruleset_mod.rules.insert(11, Rule::create_from(&["42", "31", "|", "42", "11", "31"]).unwrap());
+        messages.iter()
+            .filter(|msg| {
+                let result = ruleset_mod.fully_matches(0, msg);
+                trace!(Debug, "tested modified ruleset on {} -> {}", msg, result);
+                result
+            })
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example01() {
+        let ruleset = RuleSet::create_from("\
+0: 4 1 5
+1: 2 3 | 3 2
+2: 4 4 | 5 5
+3: 4 5 | 5 4
+4: \"a\"
+5: \"b\"").unwrap();
+        assert!(ruleset.fully_matches(0, "ababbb"));
+        assert!(!ruleset.fully_matches(0, "bababa"));
+        assert!(ruleset.fully_matches(0, "abbbab"));
+        assert!(!ruleset.fully_matches(0, "aaabbb"));
+        assert!(!ruleset.fully_matches(0, "aaaabbb"));
+    }
+}
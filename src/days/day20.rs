@@ -0,0 +1,236 @@
+use crate::answers::Answer;
+use crate::error::ParseError;
+use crate::grid::{Orientation, Pattern, SimpleGrid, View};
+use crate::parser::{self, Scanner};
+use crate::solution::Solution;
+use crate::trace;
+
+#[derive(Clone, Debug)]
+pub struct Tile {
+    id: usize,
+    tile: SimpleGrid,
+}
+
+// The top row and the left column of a view.
+fn top_border(v: &View<u8>) -> Vec<u8> {
+    (0..v.cols()).map(|col| *v.get(0, col).unwrap()).collect()
+}
+
+fn left_border(v: &View<u8>) -> Vec<u8> {
+    (0..v.rows()).map(|row| *v.get(row, 0).unwrap()).collect()
+}
+
+impl Tile {
+    fn oriented(&self, orientation: Orientation) -> Tile {
+        Tile{ id: self.id, tile: self.tile.transform(orientation) }
+    }
+
+    fn border_down(&self) -> Vec<u8> {
+        top_border(&self.tile.view(Orientation::FlipVertical))
+    }
+
+    fn border_right(&self) -> Vec<u8> {
+        left_border(&self.tile.view(Orientation::FlipHorizontal))
+    }
+
+    // Whether one of the borders, in either direction, is 'border': each
+    // one is the top border of one of the orientations.
+    fn matches_border(&self, border: &[u8]) -> bool {
+        self.tile.orientations().any(|v| top_border(&v) == border)
+    }
+}
+
+// Removes the tile with a border matching the pattern, oriented so that
+// the border is on the left.
+fn remove_tile_with_pattern_left(tiles: &mut Vec<Tile>, pattern: Vec<u8>) -> Option<Tile> {
+    for i in 0..tiles.len() {
+        let found = tiles[i].tile.orientations().find(|v| left_border(v) == pattern).map(|v| v.orientation());
+        if let Some(orientation) = found {
+            return Some(tiles.remove(i).oriented(orientation));
+        }
+    }
+    None
+}
+
+// Removes the tile with a border matching the pattern, oriented so that
+// the border is at the top.
+fn remove_tile_with_pattern_up(tiles: &mut Vec<Tile>, pattern: Vec<u8>) -> Option<Tile> {
+    for i in 0..tiles.len() {
+        let found = tiles[i].tile.orientations().find(|v| top_border(v) == pattern).map(|v| v.orientation());
+        if let Some(orientation) = found {
+            return Some(tiles.remove(i).oriented(orientation));
+        }
+    }
+    None
+}
+
+fn find_corners(tiles: &[Tile]) -> Vec<(usize, Vec<u8>)> {
+    let mut corner_patterns = Vec::new();
+    for i in 0..tiles.len() {
+        // Check whether the four borders appear in other tiles: the top
+        // borders of the tile turned by 0 to 3 quarter turns clockwise,
+        // that is the top, left, bottom and right borders.
+        let turns = [Orientation::Identity, Orientation::RotateRight, Orientation::RotateHalf, Orientation::RotateLeft];
+        let borders = turns.map(|o| top_border(&tiles[i].tile.view(o)));
+        let is_shared_border = borders.clone().map(|border| {
+            (0..tiles.len()).any(|j| j != i && tiles[j].matches_border(&border))
+        });
+
+        // When two consecutive borders are not shared, the tile is a corner,
+        // which can be turned to have them at the top and on the left.
+        for t in 0..4 {
+            if !is_shared_border[t] && !is_shared_border[(t + 1) % 4] {
+                corner_patterns.push((tiles[i].id, borders[t].clone()));
+            }
+        }
+    }
+    corner_patterns
+}
+
+fn compose_image(tiles: Vec<Vec<Tile>>) -> SimpleGrid {
+    let tile_rows = tiles[0][0].tile.rows();
+    let tile_cols = tiles[0][0].tile.cols();
+    let rows = tiles.len() * (tile_rows - 2);
+    let cols = tiles[0].len() * (tile_cols - 2);
+
+    let mut image = SimpleGrid::new(rows, cols);
+    for (tr, tile_row) in tiles.iter().enumerate() {
+        for (tc, tile) in tile_row.iter().enumerate() {
+            for r in 1..tile_rows - 1 {
+                for c in 1..tile_cols - 1 {
+                    let new_r = tr * (tile_rows - 2) + r - 1;
+                    let new_c = tc * (tile_cols - 2) + c - 1;
+                    image.set(new_r, new_c, *tile.tile.get(r, c).unwrap());
+                }
+            }
+        }
+    }
+    image
+}
+
+// Arranges the tiles into a square, rotating and flipping them so that
+// the borders of adjacent tiles match, if possible.
+fn arrange_tiles(tiles: &[Tile]) -> Option<Vec<Vec<Tile>>> {
+    let mut tiles = tiles.to_vec();
+    let n_tiles = tiles.len();
+
+    let corner_patterns = find_corners(&tiles);
+    if corner_patterns.len() != 4 {
+        return None;
+    }
+
+    // Choose a pattern as reference to be the top border on the top-left corner
+    let mut pattern = corner_patterns[0].1.clone();
+
+    let mut arranged_tiles = Vec::new();
+
+    while let Some(tile) = remove_tile_with_pattern_up(&mut tiles, pattern) {
+        pattern = tile.border_right();
+        let mut image_row = vec![tile];
+
+        while let Some(tile) = remove_tile_with_pattern_left(&mut tiles, pattern) {
+            pattern = tile.border_right();
+            image_row.push(tile);
+        }
+
+        if image_row.len() * image_row.len() != n_tiles {
+            return None;
+        }
+
+        pattern = image_row[0].border_down();
+        arranged_tiles.push(image_row);
+    }
+
+    tiles.is_empty().then_some(arranged_tiles)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u32 = 20;
+    const TITLE: &'static str = "Jurassic Jigsaw";
+
+    type Input = Vec<Tile>;
+    type Answer1 = Answer;
+    type Answer2 = Answer;
+
+    fn parse(input: &str) -> Result<Vec<Tile>, ParseError> {
+        let mut tiles: Vec<Tile> = Vec::new();
+
+        for t in parser::sections(input) {
+            let mut sc = Scanner::new(t);
+            sc.literal("Tile ")?;
+            let id = sc.integer()?;
+            sc.literal(":\n")?;
+            let grid = sc.rest();
+            if let Some(p) = grid.find(|c| !".#\n".contains(c)) {
+                return Err(ParseError::new("expected '.' or '#'", &grid[p..]));
+            }
+            let tile = SimpleGrid::create_from(grid)?;
+            if tile.rows() != tile.cols() || tile.rows() < 2 {
+                return Err(ParseError::new("tiles must be square", grid));
+            }
+            if tiles.first().is_some_and(|first| first.tile.rows() != tile.rows()) {
+                return Err(ParseError::new("tiles must all have the same size", grid));
+            }
+
+            tiles.push(Tile{ id, tile });
+        }
+
+        Ok(tiles)
+    }
+
+    fn part1(tiles: &Vec<Tile>) -> Answer {
+        let corner_patterns = find_corners(tiles);
+        if corner_patterns.len() != 4 {
+            return Answer::Unsolved.with_detail(format!("{} corners instead of 4", corner_patterns.len()));
+        }
+
+        corner_patterns.iter().try_fold(1i128, |product, (id, _)| product.checked_mul(*id as i128))
+            .map_or(Answer::Unsolved.with_detail("the product overflows"), Answer::from)
+    }
+
+    fn part2(tiles: &Vec<Tile>) -> Answer {
+        let Some(arranged) = arrange_tiles(tiles) else {
+            return Answer::Unsolved.with_detail("the tiles do not fit in a square");
+        };
+        let mut image = compose_image(arranged);
+
+        let monster = SimpleGrid::create_from("\
+..................#.
+#....##....##....###
+.#..#..#..#..#..#...").unwrap();
+        let monster = Pattern::in_all_orientations(&monster, Some(b'.'));
+
+        // The monsters are only in one orientation of the image.
+        let matches = image.find_disjoint(&monster);
+        let Some(orientation) = matches.first().map(|m| m.orientation) else {
+            return Answer::Unsolved.with_detail("no monsters found");
+        };
+        for m in matches.iter().filter(|m| m.orientation == orientation) {
+            trace!(Debug, "found monster at row {}, col {}", m.row, m.col);
+            image.stamp(&monster, m, b'O');
+        }
+
+        trace!(Info, "image with the monsters:\n{}", image.transform(orientation.inverse()));
+
+        Answer::from(image.values().filter(|c| **c == b'#').count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unsolved() {
+        // a single tile is a square, but has no monsters
+        let tile = Day20::parse("Tile 2:\n#.\n##").unwrap();
+        assert_eq!(Day20::part1(&tile), Answer::Int(16));
+        assert_eq!(Day20::part2(&tile), Answer::Unsolved.with_detail("no monsters found"));
+        // two tiles which do not match have 8 corners
+        let tiles = Day20::parse("Tile 1:\n#.\n..\n\nTile 2:\n##\n##").unwrap();
+        assert!(!Day20::part1(&tiles).is_solved() && !Day20::part2(&tiles).is_solved());
+        assert!(!Day20::part1(&Day20::parse("").unwrap()).is_solved());
+    }
+}
@@ -0,0 +1,103 @@
+use crate::error::ParseError;
+use crate::parser::{self, Scanner};
+use crate::solution::Solution;
+use crate::trace;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+#[derive(Clone, Debug)]
+pub struct Food {
+    ingredients: HashSet<String>,
+    allergens: Vec<String>,
+}
+
+impl Food {
+    fn create_from(s: &str) -> Result<Food, ParseError> {
+        let mut sc = Scanner::new(s);
+        let ingredients = sc.take_while(|c| c != '(').split_whitespace().map(String::from).collect();
+        let mut allergens = Vec::new();
+        if sc.try_literal("(contains ") {
+            allergens = parser::list(sc.until(")")?, ", ", |a| Ok(a.to_string()))?;
+        }
+        sc.end()?;
+        Ok(Food{ ingredients, allergens })
+    }
+}
+
+// Returns a map between an allergen and the ingredients that may hold it.
+fn potentially_dangerous(food: &[Food]) -> HashMap<String, HashSet<String>> {
+    let mut potentially_dangerous: HashMap<String, HashSet<String>> = HashMap::new();
+
+    // The ingredient associated with a certain allergen must appear in all
+    // the food items with that allergen.
+    for Food{ ingredients, allergens } in food {
+        for a in allergens {
+            match potentially_dangerous.entry(a.to_string()) {
+                Entry::Occupied(mut e) => {
+                    e.insert(e.get().intersection(ingredients).cloned().collect());
+                },
+                Entry::Vacant(e) => {
+                    e.insert(ingredients.clone());
+                },
+            }
+        }
+    }
+
+    trace!(Debug, "potentially dangerous food: {:?}", potentially_dangerous);
+
+    potentially_dangerous
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u32 = 21;
+    const TITLE: &'static str = "Allergen Assessment";
+
+    type Input = Vec<Food>;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Vec<Food>, ParseError> {
+        parser::lines(input, Food::create_from)
+    }
+
+    fn part1(food: &Vec<Food>) -> usize {
+        let potentially_dangerous = potentially_dangerous(food);
+
+        food.iter()
+            .flat_map(|Food{ ingredients, .. }| ingredients.iter())
+            .filter(|i| potentially_dangerous.values().all(|ing_list| !ing_list.contains(*i)))
+            .count()
+    }
+
+    fn part2(food: &Vec<Food>) -> String {
+        let mut potentially_dangerous = potentially_dangerous(food);
+        let mut dangerous = Vec::new();
+
+        while let Some((a, _)) = potentially_dangerous.iter().find(|(_, list)| list.len() == 1) {
+            let allergen = a.clone();
+            let ing_list = potentially_dangerous.remove(&allergen).unwrap();
+            let ingredient = ing_list.into_iter().next().unwrap();
+
+            for ing_list in potentially_dangerous.values_mut() {
+                ing_list.remove(&ingredient);
+            }
+
+            dangerous.push((allergen, ingredient));
+        }
+
+        if !potentially_dangerous.is_empty() {
+            panic!("the problem does not have a unique solution");
+        }
+
+        trace!(Debug, "dangerous food: {:?}", dangerous);
+
+        dangerous.sort();
+        dangerous.into_iter()
+            .map(|(_, ingredient)| ingredient)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
@@ -0,0 +1,169 @@
+use crate::answers::Answer;
+use crate::error::{parse_number, ParseError};
+use crate::parser::{self, Scanner};
+use crate::solution::Solution;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::hash::{Hash, Hasher};
+
+#[derive(Debug, PartialEq)]
+pub enum GameResult {
+    P1Wins(usize),
+    P2Wins(usize),
+    InfiniteLoop,
+}
+
+// The answer is the score of the winner.
+impl From<GameResult> for Answer {
+    fn from(result: GameResult) -> Answer {
+        match result {
+            GameResult::P1Wins(score) => Answer::from(score).with_detail("player 1 wins"),
+            GameResult::P2Wins(score) => Answer::from(score).with_detail("player 2 wins"),
+            GameResult::InfiniteLoop => Answer::Unsolved.with_detail("infinite loop"),
+        }
+    }
+}
+
+fn hash(p1: &VecDeque<u8>, p2: &VecDeque<u8>) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    p1.hash(&mut hasher);
+    p2.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn score(p: &VecDeque<u8>) -> usize {
+    p.iter().enumerate().map(|(i, c)| (p.len() - i) * *c as usize).sum()
+}
+
+fn play(p1: &mut VecDeque<u8>, p2: &mut VecDeque<u8>) -> GameResult {
+    let mut seen = HashSet::new();
+
+    loop {
+        if !seen.insert(hash(p1, p2)) {
+            return GameResult::InfiniteLoop;
+        }
+
+        let card_p1 = p1.pop_front().unwrap();
+        let card_p2 = p2.pop_front().unwrap();
+
+        if card_p1 > card_p2 {
+            p1.push_back(card_p1);
+            p1.push_back(card_p2);
+        }
+        else {
+            p2.push_back(card_p2);
+            p2.push_back(card_p1);
+        }
+
+        if p1.is_empty() {
+            return GameResult::P2Wins(score(p2));
+        }
+        if p2.is_empty() {
+            return GameResult::P1Wins(score(p1));
+        }
+    }
+}
+
+fn play_recursive(p1: &mut VecDeque<u8>, p2: &mut VecDeque<u8>) -> GameResult {
+    let mut seen = HashSet::new();
+
+    loop {
+        if !seen.insert(hash(p1, p2)) {
+            return GameResult::InfiniteLoop;
+        }
+
+        let card_p1 = p1.pop_front().unwrap();
+        let card_p2 = p2.pop_front().unwrap();
+
+        if card_p1 as usize <= p1.len() && card_p2 as usize <= p2.len() {
+            let mut sub_p1 = p1.clone();
+            sub_p1.truncate(card_p1 as usize);
+            let mut sub_p2 = p2.clone();
+            sub_p2.truncate(card_p2 as usize);
+            match play_recursive(&mut sub_p1, &mut sub_p2) {
+                GameResult::InfiniteLoop | GameResult::P1Wins(_) => {
+                    p1.push_back(card_p1);
+                    p1.push_back(card_p2);
+                },
+                GameResult::P2Wins(_) => {
+                    p2.push_back(card_p2);
+                    p2.push_back(card_p1);
+                },
+            }
+        } else {
+            if card_p1 > card_p2 {
+                p1.push_back(card_p1);
+                p1.push_back(card_p2);
+            }
+            else {
+                p2.push_back(card_p2);
+                p2.push_back(card_p1);
+            }
+        }
+
+        if p1.is_empty() {
+            return GameResult::P2Wins(score(p2));
+        }
+        if p2.is_empty() {
+            return GameResult::P1Wins(score(p1));
+        }
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u32 = 22;
+    const TITLE: &'static str = "Crab Combat";
+
+    type Input = (VecDeque<u8>, VecDeque<u8>);
+    type Answer1 = GameResult;
+    type Answer2 = GameResult;
+
+    fn parse(input: &str) -> Result<(VecDeque<u8>, VecDeque<u8>), ParseError> {
+        let read_deck = |s, header| -> Result<VecDeque<u8>, ParseError> {
+            let mut sc = Scanner::new(s);
+            sc.literal(header)?;
+            parser::lines(sc.rest(), |s| match parse_number(s)? {
+                0 => Err(ParseError::new("the cards must be positive", s)),
+                card => Ok(card),
+            })
+        };
+        let (p1, p2) = match parser::sections(input).collect::<Vec<_>>()[..] {
+            [p1, p2] => (p1, p2),
+            _ => { return Err(ParseError::new("expected the decks of two players", input)); },
+        };
+        Ok((read_deck(p1, "Player 1:\n")?, read_deck(p2, "Player 2:\n")?))
+    }
+
+    fn part1((p1, p2): &(VecDeque<u8>, VecDeque<u8>)) -> GameResult {
+        play(&mut p1.clone(), &mut p2.clone())
+    }
+
+    fn part2((p1, p2): &(VecDeque<u8>, VecDeque<u8>)) -> GameResult {
+        play_recursive(&mut p1.clone(), &mut p2.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example02() {
+        let mut p1 = VecDeque::from(vec![43, 19]);
+        let mut p2 = VecDeque::from(vec![2, 29, 14]);
+        assert_eq!(play_recursive(&mut p1, &mut p2), GameResult::InfiniteLoop);
+    }
+
+    #[test]
+    fn invalid() {
+        let e = Day22::parse("Player 1:\n0\n2\n\nPlayer 2:\n1\n3").unwrap_err();
+        assert_eq!(e.to_string(), "the cards must be positive at '0'");
+        // plain Combat can loop too
+        let input = Day22::parse("Player 1:\n1\n\nPlayer 2:\n1").unwrap();
+        assert_eq!(Day22::part1(&input), GameResult::P2Wins(3));
+        let input = Day22::parse("Player 1:\n2\n\nPlayer 2:\n1\n1\n1").unwrap();
+        assert_eq!(Day22::part1(&input), GameResult::InfiniteLoop);
+    }
+}
@@ -0,0 +1,190 @@
+use crate::error::ParseError;
+use crate::params::{Param, Params};
+use crate::solution::Solution;
+
+struct Cups {
+    // Position i represents the cup with label 'i+1'.
+    // next[i] indicates the cup next to i in the circle.
+    next: Vec<usize>,
+    current: usize,
+}
+
+impl Cups {
+    fn create_from(input: &str, n_cups: usize) -> Cups {
+        let input_cups: Vec<_> = input.chars().map(|c| c.to_digit(10).unwrap() as usize - 1).collect();
+        let mut next = vec![0; n_cups];
+        let mut current = input_cups[0];
+        let mut i = 1;
+        while i < n_cups {
+            next[current] = *input_cups.get(i).unwrap_or(&i);
+            current = next[current];
+            i += 1;
+        }
+        next[current] = input_cups[0];
+        current = next[current];
+
+        Cups{ next, current }
+    }
+
+    fn do_moves(&mut self, n_moves: usize) {
+        let n = self.next.len();
+
+        for _ in 0..n_moves {
+            // detach 3 cups from the chain
+            let c1 = self.next[self.current];
+            let c2 = self.next[c1];
+            let c3 = self.next[c2];
+            self.next[self.current] = self.next[c3];
+
+            // find destination
+            let mut dest = (self.current + n - 1) % n;
+            while dest == c1 || dest == c2 || dest == c3 {
+                dest = (dest + n - 1) % n;
+            }
+
+            // reattach the 3 cups after the destination cup
+            let cd = self.next[dest];
+            self.next[dest] = c1;
+            self.next[c3] = cd;
+
+            // advance the current cup
+            self.current = self.next[self.current];
+        }
+    }
+
+    fn to_string_from_1(&self) -> String {
+        let mut s = String::new();
+        let mut c = 0;
+        for _ in 1..self.next.len() {
+            c = self.next[c];
+            s.push_str(&(c+1).to_string());
+        }
+        s
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u32 = 23;
+    const TITLE: &'static str = "Crab Cups";
+
+    const PARAMS: &'static [Param] = &[
+        Param{ name: "cups1", default: "9", help: "the number of cups of part 1" },
+        Param{ name: "moves1", default: "100", help: "the number of moves of part 1" },
+        Param{ name: "cups2", default: "1000000", help: "the number of cups of part 2" },
+        Param{ name: "moves2", default: "10000000", help: "the number of moves of part 2" },
+    ];
+
+    // The labels of the cups and the (cups, moves) of the two parts.
+    type Input = (String, [(usize, usize); 2]);
+    type Answer1 = String;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<(String, [(usize, usize); 2]), ParseError> {
+        Self::parse_with(input, &Params::new(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<(String, [(usize, usize); 2]), ParseError> {
+        let input = input.trim_end();
+        if let Some(p) = input.find(|c: char| !('1'..='9').contains(&c)) {
+            return Err(ParseError::new("expected a cup label between 1 and 9", &input[p..]));
+        }
+        // The labels must be 1, 2, ... up to the number of cups, in any order.
+        let mut labels: Vec<_> = input.chars().collect();
+        labels.sort_unstable();
+        if labels.is_empty() || labels.iter().zip('1'..).any(|(l, expected)| *l != expected) {
+            return Err(ParseError::new("the cup labels must be distinct and consecutive from 1", input));
+        }
+        let mut game = [(0, 0); 2];
+        for ((cups, moves), part) in game.iter_mut().zip(["1", "2"]) {
+            let name = format!("cups{}", part);
+            *cups = params.get(&name)?;
+            if *cups < labels.len().max(4) {
+                return Err(ParseError::new(format!("{} must be at least {}", name, labels.len().max(4)), params.get_str(&name)));
+            }
+            *moves = params.get(&format!("moves{}", part))?;
+        }
+        Ok((input.to_string(), game))
+    }
+
+    fn part1((labels, game): &(String, [(usize, usize); 2])) -> String {
+        let (n_cups, n_moves) = game[0];
+        let mut cups = Cups::create_from(labels, n_cups);
+        cups.do_moves(n_moves);
+        cups.to_string_from_1()
+    }
+
+    fn part2((labels, game): &(String, [(usize, usize); 2])) -> usize {
+        let (n_cups, n_moves) = game[1];
+        let mut cups1mil = Cups::create_from(labels, n_cups);
+        cups1mil.do_moves(n_moves);
+        let c1 = cups1mil.next[0];
+        let c2 = cups1mil.next[c1];
+        (c1+1) * (c2+1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::property::{self, Config};
+
+    // Plays the game on a plain list of labels, kept rotated so that the
+    // current cup is first. Returns the labels after cup 1.
+    fn naive_game(labels: &str, n_cups: usize, n_moves: usize) -> Vec<usize> {
+        let mut circle: Vec<usize> = labels.chars().map(|c| c.to_digit(10).unwrap() as usize).collect();
+        circle.extend(circle.len()+1..=n_cups);
+        for _ in 0..n_moves {
+            let picked: Vec<_> = circle.drain(1..4).collect();
+            let mut dest = circle[0];
+            loop {
+                dest = if dest == 1 { n_cups } else { dest - 1 };
+                if !picked.contains(&dest) { break; }
+            }
+            let p = circle.iter().position(|c| *c == dest).unwrap();
+            circle.splice(p+1..p+1, picked);
+            circle.rotate_left(1);
+        }
+        let p = circle.iter().position(|c| *c == 1).unwrap();
+        circle.rotate_left(p);
+        circle.split_off(1)
+    }
+
+    #[test]
+    fn linked_cups_match_naive() {
+        property::check(&Config::default(),
+            |rng, size| {
+                let mut labels: Vec<_> = ('1'..='9').take(1 + rng.below(size.min(9))).collect();
+                rng.shuffle(&mut labels);
+                let min_cups = labels.len().max(4);
+                let cups = min_cups + rng.below(size);
+                let moves = rng.below(10 * size);
+                (labels.into_iter().collect::<String>(), cups as i64, moves as i64)
+            },
+            |(labels, cups, moves)| {
+                let min_cups = labels.len().max(4) as i64;
+                let mut simpler: Vec<_> = property::shrink_int(*moves, 0).into_iter()
+                    .map(|m| (labels.clone(), *cups, m))
+                    .chain(property::shrink_int(*cups, min_cups).into_iter().map(|c| (labels.clone(), c, *moves)))
+                    .collect();
+                if labels.len() > 1 {
+                    let max_label = char::from_digit(labels.len() as u32, 10).unwrap();
+                    simpler.push((labels.replace(max_label, ""), *cups, *moves));
+                }
+                simpler
+            },
+            |(labels, cups, moves)| {
+                let mut params = Params::new(Day23::PARAMS);
+                for part in ["1", "2"] {
+                    params.set(&format!("cups{}", part), &cups.to_string())?;
+                    params.set(&format!("moves{}", part), &moves.to_string())?;
+                }
+                let input = Day23::parse_with(labels, &params).map_err(|e| e.to_string())?;
+                let after_1 = naive_game(labels, *cups as usize, *moves as usize);
+                let naive1: String = after_1.iter().map(|c| c.to_string()).collect();
+                property::same(Day23::part1(&input), naive1)?;
+                property::same(Day23::part2(&input), after_1[0] * after_1[1])
+            });
+    }
+}
@@ -0,0 +1,90 @@
+use crate::automaton::{Rule, Sparse};
+use crate::error::ParseError;
+use crate::params::{Param, Params};
+use crate::parser;
+use crate::solution::Solution;
+use std::collections::HashSet;
+
+fn coordinates(directions: &str) -> Result<(i64, i64), ParseError> {
+    let mut x = 0;
+    let mut y = 0;
+    let mut chars = directions.char_indices();
+    while let Some((i, c)) = chars.next() {
+        let invalid = || ParseError::new("expected e, se, sw, w, nw or ne", &directions[i..]);
+        // Map the six directions to three vectors (v1, v2, v3) and their
+        // opposites (-v1, -v2, -v3). The mapping is arbitrary: any mapping
+        // is suitable provided that v1, v2, v3 are chosen so that
+        // v1 + v2 + v3 = 0.
+        match c {
+            'e' => { x += 2; },
+            'w' => { x -= 2; },
+            'n' => {
+                match chars.next() {
+                    Some((_, 'e')) => { x += 1; y -= 1; },
+                    Some((_, 'w')) => { x -= 1; y -= 1; },
+                    _ => { return Err(invalid()); },
+                }
+            },
+            's' => {
+                match chars.next() {
+                    Some((_, 'e')) => { x += 1; y += 1; },
+                    Some((_, 'w')) => { x -= 1; y += 1; },
+                    _ => { return Err(invalid()); },
+                }
+            },
+            _ => { return Err(invalid()); },
+        }
+    }
+    Ok((x, y))
+}
+
+// The six neighbours of a tile, in the coordinates of 'coordinates'.
+const NEIGHBOURS: [(i64, i64); 6] = [(2, 0), (1, -1), (-1, -1), (-2, 0), (-1, 1), (1, 1)];
+
+// A white tile with exactly 2 black neighbours is flipped to black, and a
+// black tile with 0 or more than 2 black neighbours is flipped to white.
+const FLIP_RULE: Rule = Rule::new(&[2], &[1, 2]);
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u32 = 24;
+    const TITLE: &'static str = "Lobby Layout";
+
+    const PARAMS: &'static [Param] = &[
+        Param{ name: "days", default: "100", help: "the number of days of part 2" },
+    ];
+
+    // The initial set of black tiles and the number of days.
+    type Input = (HashSet<(i64, i64)>, usize);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<(HashSet<(i64, i64)>, usize), ParseError> {
+        Self::parse_with(input, &Params::new(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<(HashSet<(i64, i64)>, usize), ParseError> {
+        let mut black_tiles = HashSet::new();
+
+        let tiles: Vec<_> = parser::lines(input, coordinates)?;
+        for pos in tiles {
+            if !black_tiles.insert(pos) {
+                black_tiles.remove(&pos);
+            }
+        }
+        Ok((black_tiles, params.get("days")?))
+    }
+
+    fn part1((black_tiles, _): &(HashSet<(i64, i64)>, usize)) -> usize {
+        black_tiles.len()
+    }
+
+    fn part2((black_tiles, days): &(HashSet<(i64, i64)>, usize)) -> usize {
+        let mut black_tiles = Sparse::new(black_tiles.iter().copied());
+        for _ in 0..*days {
+            black_tiles.step(&FLIP_RULE, |(x, y)| NEIGHBOURS.map(|(dx, dy)| (x+dx, y+dy)));
+        }
+        black_tiles.len()
+    }
+}
@@ -0,0 +1,63 @@
+use crate::answers::Answer;
+use crate::error::{parse_number, ParseError};
+use crate::math;
+use crate::parser::Scanner;
+use crate::solution::Solution;
+
+const P: u64 = 20201227;
+
+// Compute the logarithm of an element in Z_P by brute force search.
+fn log_p(base: u64, value: u64) -> Option<u64> {
+    let mut v = 1;
+    for exp in 0..P {
+        if v == value { return Some(exp); }
+        v = (v * base) % P;
+    }
+    None
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u32 = 25;
+    const TITLE: &'static str = "Combo Breaker";
+
+    // The public keys of the card and of the door.
+    type Input = (u64, u64);
+    type Answer1 = u64;
+    // There is no second puzzle on the last day.
+    type Answer2 = Answer;
+
+    fn parse(input: &str) -> Result<(u64, u64), ParseError> {
+        let mut sc = Scanner::new(input);
+        let mut read_pubkey = || {
+            let line = sc.until("\n")?;
+            let key = parse_number::<u64>(line)?;
+            if key == 0 || key >= P {
+                return Err(ParseError::new(format!("public keys must be between 1 and {}", P - 1), line));
+            }
+            Ok(key)
+        };
+        let card_pubkey = read_pubkey()?;
+        let door_pubkey = read_pubkey()?;
+        sc.end()?;
+        Ok((card_pubkey, door_pubkey))
+    }
+
+    fn part1(&(card_pubkey, door_pubkey): &(u64, u64)) -> u64 {
+        //let card_loops = log_p(7, card_pubkey).unwrap();
+        let door_loops = log_p(7, door_pubkey).unwrap();
+
+        //let encryption_key = math::pow_mod(7, card_loops * door_loops, P);
+        //let card_encryption_key = math::pow_mod(door_pubkey, card_loops, P);
+        let door_encryption_key = math::pow_mod(card_pubkey, door_loops, P);
+        //assert_eq!(encryption_key, card_encryption_key);
+        //assert_eq!(encryption_key, door_encryption_key);
+
+        door_encryption_key
+    }
+
+    fn part2(_: &(u64, u64)) -> Answer {
+        Answer::Unsolved.with_detail("no part 2")
+    }
+}
@@ -0,0 +1,90 @@
+use crate::solution::AnySolution;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+pub const DAYS: std::ops::RangeInclusive<u32> = 1..=25;
+
+// All the solutions, in day order.
+pub static SOLUTIONS: [&dyn AnySolution; 25] = [
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+    &day20::Day20,
+    &day21::Day21,
+    &day22::Day22,
+    &day23::Day23,
+    &day24::Day24,
+    &day25::Day25,
+];
+
+pub fn get(day: u32) -> Option<&'static dyn AnySolution> {
+    SOLUTIONS.iter().copied().find(|s| s.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+    use crate::params::Params;
+
+    #[test]
+    fn solutions_in_day_order() {
+        let days: Vec<_> = SOLUTIONS.iter().map(|s| s.day()).collect();
+        assert_eq!(days, DAYS.collect::<Vec<_>>());
+        assert!(SOLUTIONS.iter().all(|s| !s.title().is_empty()));
+    }
+
+    #[test]
+    fn inputs_saved_on_windows() {
+        // The inputs with a BOM, CRLF line endings, trailing whitespace and
+        // trailing blank lines must give the same answers.
+        for s in SOLUTIONS {
+            let Ok(input) = Source::default().read(s.day()) else { continue };
+            let mangled = format!("\u{feff}{}\r\n \r\n", input.lines().collect::<Vec<_>>().join(" \r\n"));
+            let params = Params::new(s.params());
+            let original = s.parse_input(&input, &params).unwrap();
+            let normalized = s.parse_input(&mangled, &params).unwrap();
+            assert_eq!(s.run_part1(original.as_ref()), s.run_part1(normalized.as_ref()), "day {}", s.day());
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+// An error in the puzzle input.
+//
+// Parsers create the error from the offending piece of text, which must
+// be a slice of the input: the error can then be located in the input
+// (line and column) with locate(), without the need for the parsers to
+// keep track of the position.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub text: String,
+    pub day: Option<u32>,
+    pub line: Option<usize>,   // 1-based
+    pub column: Option<usize>, // 1-based, in characters
+    addr: usize,               // address of the offending text
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>, text: &str) -> ParseError {
+        ParseError{
+            message: message.into(),
+            text: text.to_string(),
+            day: None,
+            line: None,
+            column: None,
+            addr: text.as_ptr() as usize,
+        }
+    }
+
+    // Computes line and column of the offending text, if it is a slice of 'input'.
+    pub fn locate(mut self, input: &str) -> ParseError {
+        let start = input.as_ptr() as usize;
+        if self.line.is_none() && self.addr >= start && self.addr + self.text.len() <= start + input.len() {
+            let before = &input[..self.addr - start];
+            let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+            self.line = Some(before.matches('\n').count() + 1);
+            self.column = Some(before[line_start..].chars().count() + 1);
+        }
+        self
+    }
+
+    pub fn in_day(mut self, day: u32) -> ParseError {
+        self.day = Some(day);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {:02}, ", day)?;
+        }
+        if let (Some(line), Some(column)) = (self.line, self.column) {
+            write!(f, "line {}, column {}: ", line, column)?;
+        }
+        write!(f, "{}", self.message)?;
+        match self.text.lines().next() {
+            Some(text) if text.chars().count() > 40 => {
+                write!(f, " at '{}...'", text.chars().take(40).collect::<String>())
+            },
+            Some(text) if !text.is_empty() => write!(f, " at '{}'", text),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Parses a number, reporting the text on failure.
+pub fn parse_number<T: FromStr>(s: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| ParseError::new("invalid number", s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn location() {
+        let input = "abc\ndef ghi\n\njkl";
+        let e = ParseError::new("bad", &input[8..11]).locate(input).in_day(3);
+        assert_eq!((e.line, e.column), (Some(2), Some(5)));
+        assert_eq!(e.to_string(), "day 03, line 2, column 5: bad at 'ghi'");
+
+        let e = ParseError::new("missing", &input[input.len()..]).locate(input);
+        assert_eq!((e.line, e.column), (Some(4), Some(4)));
+        assert_eq!(e.to_string(), "line 4, column 4: missing");
+
+        let other = String::from("xyz");
+        let e = ParseError::new("unrelated", &other).locate(input);
+        assert_eq!((e.line, e.column), (None, None));
+        assert_eq!(e.to_string(), "unrelated at 'xyz'");
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(parse_number::<i64>("-12"), Ok(-12));
+        assert_eq!(parse_number::<u8>("300").unwrap_err().text, "300");
+    }
+}
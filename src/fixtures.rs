@@ -0,0 +1,146 @@
+// Example inputs with their expected answers, run by a single test over
+// all the days.
+//
+// The fixtures are the files DIR/NN/NAME.txt, where NN is the day. Each
+// file starts with a header giving the expected answers (of one or both
+// parts) and optionally the parameters, ended by a line '---'; the rest
+// of the file is the input:
+//
+//     # Comments are allowed in the header.
+//     part1: 127
+//     part2: 62
+//     param: window=5
+//     ---
+//     35
+//     20
+//     ...
+//
+// Only the parts with an expected answer are run, so that examples which
+// only make sense for one part can be used too.
+
+use crate::days;
+use crate::params::{parse_assignment, Params};
+use std::path::{Path, PathBuf};
+
+// The directory with the fixtures, as DIR/NN/NAME.txt.
+pub const DEFAULT_DIR: &str = "fixtures";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Fixture {
+    pub day: u32,
+    pub name: String,
+    pub params: Vec<(String, String)>,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub input: String,
+}
+
+impl Fixture {
+    // Reads a fixture from the text of its file.
+    pub fn create_from(day: u32, name: &str, text: &str) -> Result<Fixture, String> {
+        let mut fixture = Fixture{ day, name: name.to_string(), ..Fixture::default() };
+        let mut lines = text.split_inclusive('\n');
+        for line in lines.by_ref() {
+            let line = line.trim_end();
+            if line == "---" {
+                fixture.input = lines.collect();
+                if fixture.part1.is_none() && fixture.part2.is_none() {
+                    return Err("no expected answer".to_string());
+                }
+                return Ok(fixture);
+            }
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line.split_once(": ")
+                .ok_or_else(|| format!("expected 'KEY: VALUE' in the header: {}", line))?;
+            match key {
+                "part1" => fixture.part1 = Some(value.to_string()),
+                "part2" => fixture.part2 = Some(value.to_string()),
+                "param" => fixture.params.push(parse_assignment(value)?),
+                _ => return Err(format!("unknown header field: {}", key)),
+            }
+        }
+        Err("missing '---' line after the header".to_string())
+    }
+
+    // Runs the solution of the day on the input, checking the answers.
+    pub fn run(&self) -> Result<(), String> {
+        let s = days::get(self.day).ok_or_else(|| format!("no solution for day {}", self.day))?;
+        let mut params = Params::new(s.params());
+        for (name, value) in &self.params {
+            params.set(name, value)?;
+        }
+        let input = s.parse_input(&self.input, &params).map_err(|e| e.to_string())?;
+        let answers = [(1, &self.part1), (2, &self.part2)];
+        for (part, expected) in answers.into_iter().filter_map(|(p, e)| Some((p, e.as_ref()?))) {
+            let answer = if part == 1 { s.run_part1(input.as_ref()) } else { s.run_part2(input.as_ref()) };
+            if answer.value().to_string() != *expected {
+                return Err(format!("part {} is {}, expected {}", part, answer, expected));
+            }
+        }
+        Ok(())
+    }
+}
+
+// Reads all the fixtures in a directory, by day and name.
+pub fn discover(dir: &Path) -> Result<Vec<Fixture>, String> {
+    let mut fixtures = Vec::new();
+    for day in days::DAYS {
+        let day_dir = dir.join(format!("{:02}", day));
+        if !day_dir.is_dir() {
+            continue;
+        }
+        let mut paths: Vec<PathBuf> = std::fs::read_dir(&day_dir)
+            .and_then(|entries| entries.map(|e| e.map(|e| e.path())).collect())
+            .map_err(|e| format!("{}: {}", day_dir.display(), e))?;
+        paths.retain(|p| p.extension().is_some_and(|ext| ext == "txt"));
+        paths.sort();
+        for path in paths {
+            let text = std::fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+            let name = path.file_stem().unwrap_or_default().to_string_lossy();
+            fixtures.push(Fixture::create_from(day, &name, &text).map_err(|e| format!("{}: {}", path.display(), e))?);
+        }
+    }
+    Ok(fixtures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic::{self, AssertUnwindSafe};
+
+    #[test]
+    fn format() {
+        let f = Fixture::create_from(9, "small", "# A comment.\npart2: 62\nparam: window=5\n---\n35\n20\n").unwrap();
+        assert_eq!(f, Fixture{
+            day: 9,
+            name: "small".to_string(),
+            params: vec![("window".to_string(), "5".to_string())],
+            part1: None,
+            part2: Some("62".to_string()),
+            input: "35\n20\n".to_string(),
+        });
+        assert!(Fixture::create_from(1, "x", "part1: 3\n1\n2\n").is_err());
+        assert!(Fixture::create_from(1, "x", "---\n1\n2\n").is_err());
+        assert!(Fixture::create_from(1, "x", "part3: 3\n---\n1\n").is_err());
+    }
+
+    #[test]
+    fn all_fixtures() {
+        let fixtures = discover(Path::new(DEFAULT_DIR)).unwrap();
+        let failures: Vec<_> = fixtures.iter()
+            .filter_map(|f| {
+                let result = panic::catch_unwind(AssertUnwindSafe(|| f.run()))
+                    .unwrap_or_else(|_| Err("panicked".to_string()));
+                result.err().map(|e| format!("day {:02} {}: {}", f.day, f.name, e))
+            })
+            .collect();
+        assert!(failures.is_empty(), "{} of {} fixtures failed:\n{}", failures.len(), fixtures.len(), failures.join("\n"));
+
+        // Every day has examples.
+        for day in days::DAYS {
+            assert!(fixtures.iter().any(|f| f.day == day), "no fixtures for day {}", day);
+        }
+    }
+}
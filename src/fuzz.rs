@@ -0,0 +1,330 @@
+// A mutational fuzzer for the input parsers. It mutates the inputs of the
+// fixtures (flipping bytes, truncating, duplicating or dropping lines,
+// replacing numbers with extreme ones...) and checks that parsing them
+// either succeeds or returns an error, but never panics.
+//
+// The inputs which make a parser panic are the crashers. They are saved
+// as DIR/NN/NAME.txt, with the parameters in a header like the fixtures
+// (but without answers):
+//
+//     param: window=5
+//     ---
+//     35
+//     ...
+//
+// and the crashers of DEFAULT_DIR are replayed by the tests, so that
+// they stay fixed.
+
+use crate::days;
+use crate::fixtures::Fixture;
+use crate::params::{parse_assignment, Params};
+use crate::rng::Rng;
+use std::any::Any;
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+
+// The directory of the crashers, as DIR/NN/NAME.txt.
+pub const DEFAULT_DIR: &str = "fuzz";
+
+// An input to parse, with the parameters of the day.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Case {
+    pub day: u32,
+    pub params: Vec<(String, String)>,
+    pub input: String,
+}
+
+impl Case {
+    pub fn from_fixture(f: &Fixture) -> Case {
+        Case{ day: f.day, params: f.params.clone(), input: f.input.clone() }
+    }
+
+    // Reads a case from the text of a crasher file.
+    pub fn create_from(day: u32, text: &str) -> Result<Case, String> {
+        let mut case = Case{ day, ..Case::default() };
+        let mut lines = text.split_inclusive('\n');
+        for line in lines.by_ref() {
+            let line = line.trim_end();
+            if line == "---" {
+                case.input = lines.collect();
+                return Ok(case);
+            }
+            match line.split_once(": ") {
+                Some(("param", value)) => case.params.push(parse_assignment(value)?),
+                _ => return Err(format!("expected 'param: NAME=VALUE' or '---': {}", line)),
+            }
+        }
+        Err("missing '---' line after the header".to_string())
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        for (name, value) in &self.params {
+            text += &format!("param: {}={}\n", name, value);
+        }
+        text + "---\n" + &self.input
+    }
+
+    // Parses the input, returning the panic if the parser panics (the
+    // parse errors are fine).
+    pub fn check(&self) -> Result<(), Panic> {
+        let Some(s) = days::get(self.day) else {
+            return Err(Panic{ message: format!("no solution for day {}", self.day), location: None });
+        };
+        let mut params = Params::new(s.params());
+        for (name, value) in &self.params {
+            params.set(name, value).map_err(|message| Panic{ message, location: None })?;
+        }
+        LAST_PANIC.with(|p| p.borrow_mut().take());
+        panic::catch_unwind(AssertUnwindSafe(|| s.parse_input(&self.input, &params).map(|_| ())))
+            .map(|_| ())
+            .map_err(|payload| Panic{
+                message: panic_message(payload.as_ref()),
+                location: LAST_PANIC.with(|p| p.borrow_mut().take()),
+            })
+    }
+}
+
+// The message of a panic, from the payload caught by catch_unwind.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload.downcast_ref::<&str>().map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "?".to_string())
+}
+
+// The message of a panic, with its location when known (see quiet_panics).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    pub location: Option<String>,
+}
+
+impl Display for Panic {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{} at {}", self.message, location),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+thread_local! {
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+// Replaces the panic hook by one which does not print anything, but keeps
+// the location of the panic for Case::check, so that the crashes can be
+// told apart by location.
+pub fn quiet_panics() {
+    panic::set_hook(Box::new(|info| {
+        if let Some(l) = info.location() {
+            LAST_PANIC.with(|p| *p.borrow_mut() = Some(format!("{}:{}", l.file(), l.line())));
+        }
+    }));
+}
+
+// Numbers likely to find overflows and huge allocations.
+const NUMBERS: &[&str] = &[
+    "0", "1", "-1", "255", "256", "65536", "2147483648", "4294967296", "68719476736",
+    "9223372036854775807", "18446744073709551616", "99999999999999999999999999",
+];
+
+// Bytes likely to be special for the parsers.
+const BYTES: &[u8] = b"0123456789 \n\t:,.-+=()[]{}|#&*/\\\"'xXLRFBNSEWaby";
+
+// Applies one to four random mutations to an input.
+pub fn mutate(input: &str, rng: &mut Rng) -> String {
+    let mut bytes = input.as_bytes().to_vec();
+    for _ in 0..1 + rng.below(4) {
+        mutate_once(&mut bytes, rng);
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn mutate_once(bytes: &mut Vec<u8>, rng: &mut Rng) {
+    let pos = |rng: &mut Rng, len: usize| rng.below(len + 1);
+    match rng.below(10) {
+        0 if !bytes.is_empty() => {
+            // flip a byte
+            let p = rng.below(bytes.len());
+            bytes[p] = if rng.chance(0.8) { *rng.choose(BYTES) } else { rng.below(128) as u8 };
+        },
+        1 => {
+            // insert a byte
+            let p = pos(rng, bytes.len());
+            bytes.insert(p, *rng.choose(BYTES));
+        },
+        2 if !bytes.is_empty() => {
+            // delete a few bytes
+            let p = rng.below(bytes.len());
+            let n = 1 + rng.below(8);
+            bytes.drain(p..(p + n).min(bytes.len()));
+        },
+        3 => {
+            // truncate
+            let p = pos(rng, bytes.len());
+            bytes.truncate(p);
+        },
+        4..=6 => {
+            // duplicate, delete or swap lines
+            let mut lines: Vec<Vec<u8>> = bytes.split_inclusive(|b| *b == b'\n').map(|l| l.to_vec()).collect();
+            if lines.is_empty() {
+                return;
+            }
+            let i = rng.below(lines.len());
+            let j = rng.below(lines.len());
+            match rng.below(3) {
+                0 => { let l = lines[i].clone(); lines.insert(j, l); },
+                1 => { lines.remove(i); },
+                _ => { lines.swap(i, j); },
+            }
+            *bytes = lines.concat();
+        },
+        7 | 8 => {
+            // replace a number by another one, or insert one
+            let digits: Vec<usize> = (0..bytes.len()).filter(|&i| bytes[i].is_ascii_digit()).collect();
+            let number = rng.choose(NUMBERS).as_bytes();
+            if digits.is_empty() {
+                let p = pos(rng, bytes.len());
+                bytes.splice(p..p, number.iter().copied());
+                return;
+            }
+            let mut start = *rng.choose(&digits);
+            while start > 0 && bytes[start - 1].is_ascii_digit() {
+                start -= 1;
+            }
+            let mut end = start;
+            while end < bytes.len() && bytes[end].is_ascii_digit() {
+                end += 1;
+            }
+            bytes.splice(start..end, number.iter().copied());
+        },
+        _ if !bytes.is_empty() => {
+            // copy a piece of the input elsewhere
+            let p = rng.below(bytes.len());
+            let piece = bytes[p..(p + 1 + rng.below(16)).min(bytes.len())].to_vec();
+            let q = pos(rng, bytes.len());
+            bytes.splice(q..q, piece);
+        },
+        _ => {},
+    }
+}
+
+// A crash found while fuzzing.
+#[derive(Clone, Debug)]
+pub struct Crash {
+    pub case: Case,
+    pub panic: Panic,
+}
+
+// Fuzzes the parsers of the days of the seed cases, for the given number
+// of iterations (or forever), calling 'on_crash' for each crash. Each
+// iteration mutates a seed case, or a mutated case that did not crash,
+// to go further from the examples. Returns the number of iterations.
+pub fn run(seeds: &[Case], seed: u64, iterations: Option<u64>, mut on_crash: impl FnMut(u64, &Crash)) -> u64 {
+    assert!(!seeds.is_empty(), "no seed case");
+    let mut rng = Rng::new(seed);
+    let mut last: Option<Case> = None;
+    let mut i = 0;
+    while iterations.is_none_or(|n| i < n) {
+        let base = match last.take() {
+            Some(case) if rng.chance(0.5) => case,
+            _ => rng.choose(seeds).clone(),
+        };
+        let case = Case{ input: mutate(&base.input, &mut rng), ..base };
+        match case.check() {
+            Ok(()) => last = Some(case),
+            Err(panic) => on_crash(i, &Crash{ case, panic }),
+        }
+        i += 1;
+    }
+    i
+}
+
+// Reads the crashers of a directory, with their paths.
+pub fn read_crashers(dir: &Path) -> Result<Vec<(PathBuf, Case)>, String> {
+    let mut crashers = Vec::new();
+    for day in days::DAYS {
+        let day_dir = dir.join(format!("{:02}", day));
+        if !day_dir.is_dir() {
+            continue;
+        }
+        let mut paths: Vec<PathBuf> = std::fs::read_dir(&day_dir)
+            .and_then(|entries| entries.map(|e| e.map(|e| e.path())).collect())
+            .map_err(|e| format!("{}: {}", day_dir.display(), e))?;
+        paths.retain(|p| p.extension().is_some_and(|ext| ext == "txt"));
+        paths.sort();
+        for path in paths {
+            let text = std::fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+            let case = Case::create_from(day, &text).map_err(|e| format!("{}: {}", path.display(), e))?;
+            crashers.push((path, case));
+        }
+    }
+    Ok(crashers)
+}
+
+// Saves a crasher as DIR/NN/NAME.txt, named after a hash of its content
+// so that the same crasher is only saved once. Returns its path.
+pub fn save_crasher(dir: &Path, case: &Case) -> Result<PathBuf, String> {
+    let text = case.to_text();
+    // FNV-1a
+    let hash = text.bytes().fold(0xcbf29ce484222325u64, |h, b| (h ^ b as u64).wrapping_mul(0x100000001b3));
+    let day_dir = dir.join(format!("{:02}", case.day));
+    std::fs::create_dir_all(&day_dir).map_err(|e| format!("{}: {}", day_dir.display(), e))?;
+    let path = day_dir.join(format!("{:016x}.txt", hash));
+    std::fs::write(&path, text).map_err(|e| format!("{}: {}", path.display(), e))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn format() {
+        let case = Case::create_from(9, "param: window=5\n---\n35\n20\n").unwrap();
+        assert_eq!(case, Case{ day: 9, params: vec![("window".to_string(), "5".to_string())], input: "35\n20\n".to_string() });
+        assert_eq!(Case::create_from(9, &case.to_text()), Ok(case));
+        assert!(Case::create_from(9, "part1: 127\n---\n").is_err());
+        assert!(Case::create_from(9, "param: window=5\n").is_err());
+    }
+
+    #[test]
+    fn mutations() {
+        let input = "abc 123\ndef 456\n";
+        let mutated: Vec<_> = (0..100).map(|i| mutate(input, &mut Rng::new(i))).collect();
+        assert!(mutated.iter().filter(|m| *m != input).count() > 80);
+        assert_eq!(mutated[7], mutate(input, &mut Rng::new(7)));
+        assert!((0..100).all(|i| mutate("", &mut Rng::new(i)).len() <= 4 * 26));
+    }
+
+    #[test]
+    fn checks() {
+        assert_eq!(Case{ day: 1, params: vec![], input: "1\n2\n".to_string() }.check(), Ok(()));
+        assert_eq!(Case{ day: 1, params: vec![], input: "1\nx\n".to_string() }.check(), Ok(()));
+        let case = Case{ day: 25, params: vec![("x".to_string(), "1".to_string())], input: String::new() };
+        assert_eq!(case.check().unwrap_err().to_string(), "unknown parameter: x");
+    }
+
+    #[test]
+    fn crashers() {
+        // The saved crashers must not crash anymore.
+        for (path, case) in read_crashers(Path::new(DEFAULT_DIR)).unwrap() {
+            if let Err(e) = case.check() {
+                panic!("{}: {}", path.display(), e);
+            }
+        }
+    }
+
+    #[test]
+    fn short_run() {
+        let seeds: Vec<_> = fixtures::discover(Path::new(fixtures::DEFAULT_DIR)).unwrap()
+            .iter().map(Case::from_fixture).collect();
+        let mut crashes = Vec::new();
+        run(&seeds, 2020, Some(5000), |_, crash| crashes.push(crash.clone()));
+        assert!(crashes.is_empty(), "{:#?}", &crashes[..crashes.len().min(3)]);
+    }
+}
@@ -0,0 +1,1009 @@
+// Generators of random puzzle inputs, to test and benchmark the solutions
+// on inputs of any size.
+//
+// Each day has a generator producing a valid input at a given scale,
+// relative to the size of the real puzzle inputs: 1.0 gives an input
+// similar to a real one, 10.0 one about ten times larger. The inputs are
+// built so that the puzzles have a solution (e.g. the program of day 8
+// has exactly one instruction to fix), and are reproducible from the seed
+// of the Rng. Some days cannot grow much without overflowing their
+// answers or leaving what the puzzle allows (e.g. the seat IDs of day 5),
+// and clamp the scale; the inputs of days 15 and 23 are a handful of
+// numbers, which only the parameters make harder.
+//
+// When the construction fixes the answers (e.g. the corner tiles of day
+// 20), they are returned with the input.
+
+use crate::rng::Rng;
+use std::collections::{HashMap, HashSet};
+
+pub struct Generated {
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Generated {
+    fn new(input: String) -> Generated {
+        Generated{ input, part1: None, part2: None }
+    }
+
+    fn with_answers(input: String, part1: impl ToString, part2: impl ToString) -> Generated {
+        Generated{ input, part1: Some(part1.to_string()), part2: Some(part2.to_string()) }
+    }
+}
+
+type Generator = fn(&mut Rng, f64) -> Generated;
+
+static GENERATORS: [Generator; 25] = [
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10,
+    day11, day12, day13, day14, day15, day16, day17, day18, day19, day20,
+    day21, day22, day23, day24, day25,
+];
+
+// Generates an input for a day, at a scale (which must be positive).
+pub fn generate(day: u32, scale: f64, rng: &mut Rng) -> Option<Generated> {
+    assert!(scale > 0.0 && scale.is_finite(), "invalid scale");
+    let generator = GENERATORS.get((day as usize).checked_sub(1)?)?;
+    Some(generator(rng, scale))
+}
+
+// The size of something that has the given size in the real inputs.
+fn scaled(base: usize, scale: f64) -> usize {
+    (base as f64 * scale).round().max(1.0) as usize
+}
+
+// The side of a square that has the given side in the real inputs.
+fn scaled_side(base: usize, scale: f64) -> usize {
+    scaled(base, scale.sqrt())
+}
+
+fn lines(lines: impl IntoIterator<Item = String>) -> String {
+    lines.into_iter().map(|l| l + "\n").collect()
+}
+
+fn letter(rng: &mut Rng) -> char {
+    (b'a' + rng.below(26) as u8) as char
+}
+
+fn day01(rng: &mut Rng, scale: f64) -> Generated {
+    // Exactly one pair and one triple add up to 2020. All the entries but
+    // one of the pair are below 1010, and the others are above 1010 and
+    // avoid the values that would complete another pair or triple.
+    let n = scaled(200, scale).clamp(5, 800);
+    loop {
+        let a = rng.range(1..=1009);
+        let c = rng.range(2..=1009);
+        let d = rng.range(1011 - c..=1009);
+        let e = 2020 - c - d;
+        let small = [a, c, d, e];
+        let mut excluded: HashSet<i64> = small.iter().map(|x| 2020 - x).collect();
+        for (i, x) in small.iter().enumerate() {
+            excluded.extend(small[i+1..].iter().map(|y| 2020 - x - y));
+        }
+        let mut large: Vec<i64> = (1011..2020).filter(|x| !excluded.contains(x)).collect();
+        rng.shuffle(&mut large);
+
+        let mut entries = small.to_vec();
+        entries.push(2020 - a);
+        entries.extend(&large[..n-5]);
+        if count_sums(&entries, 2020) == Some((1, 1)) {
+            rng.shuffle(&mut entries);
+            return Generated::with_answers(lines(entries.iter().map(i64::to_string)), a * (2020 - a), c * d * e);
+        }
+    }
+}
+
+// The number of pairs and triples of entries adding up to the target,
+// or None if the entries are not distinct.
+fn count_sums(entries: &[i64], target: i64) -> Option<(usize, usize)> {
+    let mut v = entries.to_vec();
+    v.sort();
+    let set: HashSet<i64> = v.iter().copied().collect();
+    if set.len() != v.len() {
+        return None;
+    }
+    let pairs = v.iter().filter(|&&x| target - x > x && set.contains(&(target - x))).count();
+    let mut triples = 0;
+    for (i, &x) in v.iter().enumerate() {
+        for &y in &v[i+1..] {
+            if target - x - y > y && set.contains(&(target - x - y)) {
+                triples += 1;
+            }
+        }
+    }
+    Some((pairs, triples))
+}
+
+fn day02(rng: &mut Rng, scale: f64) -> Generated {
+    Generated::new(lines((0..scaled(1000, scale)).map(|_| {
+        let c = letter(rng);
+        let len = rng.range(3..=20);
+        let n1 = rng.range(1..=len-1);
+        let n2 = rng.range(n1+1..=len);
+        // The letter of the policy is more frequent than the others.
+        let password: String = (0..len).map(|_| if rng.chance(0.3) { c } else { letter(rng) }).collect();
+        format!("{}-{} {}: {}", n1, n2, c, password)
+    })))
+}
+
+fn day03(rng: &mut Rng, scale: f64) -> Generated {
+    // The product of the trees of part 2 must fit in 64 bits.
+    let rows = scaled(323, scale).clamp(2, 20000);
+    Generated::new(lines((0..rows).map(|_| (0..31).map(|_| if rng.chance(0.2) { '#' } else { '.' }).collect())))
+}
+
+fn day04(rng: &mut Rng, scale: f64) -> Generated {
+    const EYE_COLORS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+    let number = |rng: &mut Rng, lo, hi| rng.range(lo..=hi).to_string();
+    let digits = |rng: &mut Rng, n| (0..n).map(|_| rng.range(0..=9).to_string()).collect::<String>();
+    let hex = |rng: &mut Rng| (0..6).map(|_| *rng.choose(b"0123456789abcdef") as char).collect::<String>();
+
+    let mut passports = Vec::new();
+    let (mut complete, mut valid) = (0, 0);
+    for _ in 0..scaled(290, scale) {
+        let mut fields = Vec::new();
+        let mut all_valid = true;
+        for field in ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid"] {
+            if !rng.chance(if field == "cid" { 0.6 } else { 0.93 }) {
+                continue;
+            }
+            let ok = field == "cid" || rng.chance(0.85);
+            all_valid &= ok;
+            let value = match (field, ok) {
+                ("byr", true) => number(rng, 1920, 2002),
+                ("byr", false) => number(rng, 1900, 1919),
+                ("iyr", true) => number(rng, 2010, 2020),
+                ("iyr", false) => number(rng, 2021, 2030),
+                ("eyr", true) => number(rng, 2020, 2030),
+                ("eyr", false) => number(rng, 2000, 2019),
+                ("hgt", true) if rng.chance(0.5) => number(rng, 150, 193) + "cm",
+                ("hgt", true) => number(rng, 59, 76) + "in",
+                ("hgt", false) => number(rng, 100, 149) + if rng.chance(0.5) { "cm" } else { "" },
+                ("hcl", true) => format!("#{}", hex(rng)),
+                ("hcl", false) => hex(rng),
+                ("ecl", true) => rng.choose(&EYE_COLORS).to_string(),
+                ("ecl", false) => rng.choose(&["xry", "gmt", "zzz", "dne"]).to_string(),
+                ("pid", true) => digits(rng, 9),
+                ("pid", false) => {
+                    let len = if rng.chance(0.5) { 8 } else { 10 };
+                    digits(rng, len)
+                },
+                _ /* cid */ => number(rng, 100, 350),
+            };
+            fields.push(format!("{}:{}", field, value));
+        }
+        if fields.is_empty() {
+            continue;
+        }
+        let is_complete = fields.iter().filter(|f| !f.starts_with("cid")).count() == 7;
+        complete += is_complete as usize;
+        valid += (is_complete && all_valid) as usize;
+
+        rng.shuffle(&mut fields);
+        let mut passport = String::new();
+        for (i, f) in fields.iter().enumerate() {
+            if i > 0 {
+                passport.push(if rng.chance(0.3) { '\n' } else { ' ' });
+            }
+            passport += f;
+        }
+        passports.push(passport);
+    }
+    Generated::with_answers(passports.join("\n\n") + "\n", complete, valid)
+}
+
+fn day05(rng: &mut Rng, scale: f64) -> Generated {
+    // Consecutive seats but one, which is the missing seat of part 2.
+    let n = scaled(800, scale).clamp(3, 1000) as i64;
+    let first = rng.range(1..=1023 - n);
+    let last = first + n;
+    let missing = rng.range(first+1..=last-1);
+    let mut seats: Vec<i64> = (first..=last).filter(|&id| id != missing).collect();
+    rng.shuffle(&mut seats);
+    let pass = |id: i64| (0..10).rev()
+        .map(|bit| match (bit >= 3, id >> bit & 1) {
+            (true, 0) => 'F', (true, _) => 'B',
+            (false, 0) => 'L', (false, _) => 'R',
+        })
+        .collect::<String>();
+    Generated::with_answers(lines(seats.into_iter().map(pass)), last, missing)
+}
+
+fn day06(rng: &mut Rng, scale: f64) -> Generated {
+    let groups: Vec<String> = (0..scaled(480, scale)).map(|_| {
+        // The questions everyone in the group answered, and some more.
+        let k = rng.below(8);
+        let common = rng.sample(26, k);
+        (0..rng.range(1..=5))
+            .map(|_| {
+                let k = rng.range(1..=12) as usize;
+                let mut answers = rng.sample(26, k);
+                answers.retain(|q| !common.contains(q));
+                answers.extend(&common);
+                if answers.is_empty() {
+                    answers.push(rng.below(26));
+                }
+                rng.shuffle(&mut answers);
+                answers.into_iter().map(|q| (b'a' + q as u8) as char).collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }).collect();
+    Generated::new(groups.join("\n\n") + "\n")
+}
+
+const ADJECTIVES: [&str; 18] = [
+    "bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light",
+    "mirrored", "muted", "pale", "plaid", "posh", "shiny", "striped", "vibrant", "wavy",
+];
+
+const COLORS: [&str; 33] = [
+    "aqua", "beige", "black", "blue", "bronze", "brown", "chartreuse", "coral",
+    "crimson", "cyan", "fuchsia", "gold", "gray", "green", "indigo", "lavender",
+    "lime", "magenta", "maroon", "olive", "orange", "plum", "purple", "red",
+    "salmon", "silver", "tan", "teal", "tomato", "turquoise", "violet", "white", "yellow",
+];
+
+fn day07(rng: &mut Rng, scale: f64) -> Generated {
+    // The bags are in levels, and only contain bags of the next level, so
+    // that there are no cycles and the number of bags stays reasonable.
+    // The shiny gold bag is in the middle.
+    const LEVELS: usize = 12;
+    let n = scaled(594, scale).max(LEVELS + 1);
+    let mut names: Vec<String> = (0..n.max(ADJECTIVES.len() * COLORS.len()))
+        .map(|i| {
+            let (adjective, color) = (ADJECTIVES[i % ADJECTIVES.len()], COLORS[i / ADJECTIVES.len() % COLORS.len()]);
+            match i / (ADJECTIVES.len() * COLORS.len()) {
+                0 => format!("{} {}", adjective, color),
+                k => format!("{} {}{}", adjective, color, k + 1),
+            }
+        })
+        .filter(|name| name != "shiny gold")
+        .collect();
+    rng.shuffle(&mut names);
+    names.truncate(n - 1);
+    names.insert(0, "shiny gold".to_string());
+
+    let mut levels = vec![Vec::new(); LEVELS];
+    levels[LEVELS / 2].push(0);
+    for i in 1..n {
+        levels[i % LEVELS].push(i);
+    }
+    let mut contents = vec![Vec::new(); n];
+    for level in 0..LEVELS-1 {
+        let next = &levels[level+1];
+        for &bag in &levels[level] {
+            let k = if bag == 0 { rng.range(1..=4) } else { rng.range(0..=4) } as usize;
+            contents[bag] = rng.sample(next.len(), k.min(next.len())).into_iter().map(|i| (rng.range(1..=5), next[i])).collect();
+        }
+    }
+    // Someone contains the shiny gold bag.
+    let parent = *rng.choose(&levels[LEVELS / 2 - 1]);
+    if !contents[parent].iter().any(|&(_, bag)| bag == 0) {
+        contents[parent].push((rng.range(1..=5), 0));
+    }
+
+    let mut rules: Vec<String> = (0..n).map(|bag| {
+        let list = if contents[bag].is_empty() {
+            "no other bags".to_string()
+        } else {
+            contents[bag].iter()
+                .map(|&(count, inner)| format!("{} {} bag{}", count, names[inner], if count > 1 { "s" } else { "" }))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        format!("{} bags contain {}.", names[bag], list)
+    }).collect();
+    rng.shuffle(&mut rules);
+    Generated::new(lines(rules))
+}
+
+fn day08(rng: &mut Rng, scale: f64) -> Generated {
+    // The fixed program runs the instructions 0..=a in some order, ending
+    // with a nop at a, followed by the others. The corruption turns that
+    // nop into a jump back into 0..=a. Changing any other instruction of
+    // 0..=a still lands in 0..=a (the nops point there too), and the
+    // instructions after a are never reached, so that the fix is unique.
+    // As in the real inputs, the instructions run in blocks of consecutive
+    // ones, ending with a jump to the next block.
+    let n = scaled(650, scale).max(4) as i64;
+    let a = rng.range(n/4..=n/2);
+    let mut blocks = |first: i64, last: i64| {
+        let mut blocks = Vec::new();
+        let mut start = first;
+        while start <= last {
+            let end = (start + rng.range(0..=5)).min(last);
+            blocks.push((start..=end).collect::<Vec<_>>());
+            start = end + 1;
+        }
+        // The first block stays first, and the last one last.
+        let len = blocks.len();
+        if len > 2 {
+            rng.shuffle(&mut blocks[1..len-1]);
+        }
+        blocks.concat()
+    };
+    let mut order = blocks(0, a);
+    order.extend(blocks(a + 1, n - 1));
+    order.push(n);
+
+    let mut code = vec![("", 0); n as usize];
+    let (mut acc_loop, mut acc_fixed) = (0, 0);
+    for k in 0..n as usize {
+        let (p, q) = (order[k], order[k+1]);
+        let limit = if p <= a { a } else { n - 1 };
+        code[p as usize] = if p == a {
+            ("jmp", rng.range(0..=a) - a)
+        } else if q != p + 1 {
+            ("jmp", q - p)
+        } else if rng.chance(0.7) {
+            let arg = rng.range(-99..=99);
+            acc_fixed += arg;
+            if p <= a {
+                acc_loop += arg;
+            }
+            ("acc", arg)
+        } else {
+            ("nop", rng.range(0..=limit) - p)
+        };
+    }
+    let program = lines(code.into_iter().map(|(op, arg)| format!("{} {:+}", op, arg)));
+    Generated::with_answers(program, acc_loop, acc_fixed)
+}
+
+fn day09(rng: &mut Rng, scale: f64) -> Generated {
+    // Each number is the sum of two of the oldest numbers of its window,
+    // so that they grow slowly enough to stay in 64 bits, but the invalid
+    // one: the sum of a contiguous range of earlier numbers, which is not
+    // the sum of two numbers of its window.
+    const WINDOW: usize = 25;
+    let n = scaled(1000, scale).clamp(WINDOW + 15, 1000);
+    let invalid_pos = rng.range((WINDOW + 10) as i64..=n as i64 - 1) as usize;
+    let mut code: Vec<u64> = rng.sample(50, WINDOW).into_iter().map(|x| x as u64 + 1).collect();
+    while code.len() < n {
+        let pos = code.len();
+        let window = &code[pos-WINDOW..];
+        if pos == invalid_pos {
+            let len = rng.range(2..=10) as usize;
+            let start = rng.below(pos - len + 1);
+            let x = code[start..start+len].iter().sum();
+            let is_sum = (0..WINDOW).any(|i| (i+1..WINDOW).any(|j| window[i] + window[j] == x));
+            if !is_sum && !code.contains(&x) {
+                code.push(x);
+            }
+        } else {
+            let i = rng.below(5);
+            let j = (i + 1 + rng.below(4)) % 5;
+            code.push(window[i] + window[j]);
+        }
+    }
+    Generated{
+        part1: Some(code[invalid_pos].to_string()),
+        ..Generated::new(lines(code.iter().map(u64::to_string)))
+    }
+}
+
+fn day10(rng: &mut Rng, scale: f64) -> Generated {
+    // Differences of 1 and 3, with at most four 1s in a row as in the real
+    // inputs. The number of arrangements (part 2) is counted along the
+    // way, using differences of 3 when it would not fit in 64 bits.
+    const LIMIT: i64 = 1 << 60;
+    let mut joltages = vec![0];
+    let mut arrangements = vec![1];
+    let (mut ones, mut threes, mut run) = (0, 0, 0);
+    for _ in 0..scaled(100, scale).max(2) {
+        let last = *joltages.last().unwrap();
+        let count = |x: i64| joltages.iter().zip(&arrangements).rev().take(3)
+            .filter(|&(j, _)| x - j <= 3)
+            .map(|(_, a)| a)
+            .sum::<i64>();
+        let (next, ways) = if run < 4 && rng.chance(0.6) && count(last + 1) < LIMIT {
+            ones += 1;
+            run += 1;
+            (last + 1, count(last + 1))
+        } else {
+            threes += 1;
+            run = 0;
+            (last + 3, count(last + 3))
+        };
+        joltages.push(next);
+        arrangements.push(ways);
+    }
+    let part2 = *arrangements.last().unwrap();
+    let mut adapters = joltages.split_off(1);
+    rng.shuffle(&mut adapters);
+    // The device is 3 jolts above the last adapter.
+    Generated::with_answers(lines(adapters.iter().map(i64::to_string)), ones * (threes + 1), part2)
+}
+
+fn day11(rng: &mut Rng, scale: f64) -> Generated {
+    // In random layouts, some seats can change forever (in cycles of two
+    // rounds), which the real inputs avoid: a few of the seats still
+    // changing after many rounds become floor, until the seats settle with
+    // the rules of both parts.
+    let (rows, cols) = (scaled_side(90, scale), scaled_side(95, scale));
+    let mut layout: Vec<Vec<u8>> = (0..rows)
+        .map(|_| (0..cols).map(|_| if rng.chance(0.85) { b'L' } else { b'.' }).collect())
+        .collect();
+    while let Some(mut unsettled) = [(4, false), (5, true)].into_iter()
+        .map(|(threshold, visible)| unsettled_seats(&layout, threshold, visible))
+        .find(|seats| !seats.is_empty()) {
+        rng.shuffle(&mut unsettled);
+        for &(r, c) in &unsettled[..(unsettled.len() / 20).max(1)] {
+            layout[r][c] = b'.';
+        }
+    }
+    Generated::new(lines(layout.into_iter().map(|row| String::from_utf8(row).unwrap())))
+}
+
+// The seats still changing after many rounds of day 11, where a seat
+// becomes empty when at least 'threshold' of the adjacent seats (or of
+// the first seats visible in each direction) are occupied.
+fn unsettled_seats(layout: &[Vec<u8>], threshold: usize, visible: bool) -> Vec<(usize, usize)> {
+    let (rows, cols) = (layout.len() as i64, layout[0].len() as i64);
+    let seats: Vec<(usize, usize)> = (0..rows as usize)
+        .flat_map(|r| (0..cols as usize).map(move |c| (r, c)))
+        .filter(|&(r, c)| layout[r][c] != b'.')
+        .collect();
+    let index: HashMap<(usize, usize), usize> = seats.iter().enumerate().map(|(i, &s)| (s, i)).collect();
+    let neighbors: Vec<Vec<usize>> = seats.iter().map(|&(r, c)| {
+        let mut list = Vec::new();
+        for (dr, dc) in [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)] {
+            let (mut y, mut x) = (r as i64 + dr, c as i64 + dc);
+            while visible && (0..rows).contains(&y) && (0..cols).contains(&x) && layout[y as usize][x as usize] == b'.' {
+                (y, x) = (y + dr, x + dc);
+            }
+            if let Some(&i) = index.get(&(y as usize, x as usize)) {
+                list.push(i);
+            }
+        }
+        list
+    }).collect();
+
+    let mut occupied = vec![false; seats.len()];
+    let mut changed = Vec::new();
+    for _ in 0..2 * (rows + cols) + 50 {
+        changed = (0..seats.len())
+            .filter(|&i| {
+                let n = neighbors[i].iter().filter(|&&j| occupied[j]).count();
+                if occupied[i] { n >= threshold } else { n == 0 }
+            })
+            .collect();
+        if changed.is_empty() {
+            break;
+        }
+        for &i in &changed {
+            occupied[i] = !occupied[i];
+        }
+    }
+    changed.into_iter().map(|i| seats[i]).collect()
+}
+
+fn day12(rng: &mut Rng, scale: f64) -> Generated {
+    Generated::new(lines((0..scaled(780, scale)).map(|_| {
+        let action = *rng.choose(b"NSEWLRFFF") as char;
+        let value = match action {
+            'L' | 'R' => rng.range(1..=3) * 90,
+            'F' => rng.range(1..=100),
+            _ => rng.range(1..=5),
+        };
+        format!("{}{}", action, value)
+    })))
+}
+
+fn day13(rng: &mut Rng, scale: f64) -> Generated {
+    // Distinct primes, whose product is small enough for the Chinese
+    // remainder theorem of part 2 to fit in 64 bits.
+    const MAX_PRODUCT: i64 = 1_000_000_000_000;
+    let len = scaled(60, scale).clamp(1, 1000);
+    let wanted = scaled(9, scale).clamp(1, len.min(20));
+    let mut primes: Vec<i64> = (5..=600).filter(|&n| (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0)).collect();
+    rng.shuffle(&mut primes);
+    let mut busses = Vec::new();
+    let mut product = 1;
+    for p in primes {
+        if busses.len() < wanted && product * p <= MAX_PRODUCT {
+            product *= p;
+            busses.push(p);
+        }
+    }
+    let mut list = vec!["x".to_string(); len];
+    let mut positions = vec![0];
+    positions.extend(rng.sample(len - 1, busses.len() - 1).into_iter().map(|p| p + 1));
+    for (pos, bus) in positions.into_iter().zip(busses) {
+        list[pos] = bus.to_string();
+    }
+    Generated::new(format!("{}\n{}\n", rng.range(1_000_000..=1_010_000), list.join(",")))
+}
+
+fn day14(rng: &mut Rng, scale: f64) -> Generated {
+    // At most 9 floating bits, as in the real inputs.
+    let mut program = Vec::new();
+    while program.len() < scaled(580, scale) {
+        let k = rng.range(0..=9) as usize;
+        let floating = rng.sample(36, k);
+        let mask: String = (0..36)
+            .map(|i| if floating.contains(&i) { 'X' } else if rng.chance(0.5) { '1' } else { '0' })
+            .collect();
+        program.push(format!("mask = {}", mask));
+        for _ in 0..rng.range(1..=6) {
+            program.push(format!("mem[{}] = {}", rng.range(0..=65535), rng.range(0..=1 << 30)));
+        }
+    }
+    Generated::new(lines(program))
+}
+
+fn day15(rng: &mut Rng, scale: f64) -> Generated {
+    let n = scaled(7, scale).clamp(1, 30);
+    let numbers: Vec<String> = rng.sample(20 * n, n).iter().map(usize::to_string).collect();
+    Generated::new(numbers.join(",") + "\n")
+}
+
+const TICKET_FIELDS: [&str; 20] = [
+    "departure location", "departure station", "departure platform", "departure track",
+    "departure date", "departure time", "arrival location", "arrival station",
+    "arrival platform", "arrival track", "class", "duration", "price", "route", "row",
+    "seat", "train", "type", "wagon", "zone",
+];
+
+fn day16(rng: &mut Rng, scale: f64) -> Generated {
+    // The fields form a chain: the values of field i are at most upper[i],
+    // which grows with i, and each column has a value above the upper
+    // limit of the previous field of the chain. So the column of field j
+    // only fits the fields i >= j: field 0 has a single possible column,
+    // then field 1 has a single one left, and so on.
+    let n = TICKET_FIELDS.len();
+    let mut names = TICKET_FIELDS.to_vec();
+    rng.shuffle(&mut names);
+    let upper: Vec<i64> = (0..n).map(|i| 50 + 40 * i as i64 + rng.range(20..=39)).collect();
+    let max = upper[n-1];
+    let field_of: Vec<usize> = rng.sample(n, n);
+    let ticket = |rng: &mut Rng| (0..n).map(|col| rng.range(30..=upper[field_of[col]])).collect::<Vec<_>>();
+
+    let mine = ticket(rng);
+    let count = scaled(240, scale);
+    let mut nearby: Vec<Vec<i64>> = (0..count).map(|_| ticket(rng)).collect();
+    for col in 0..n {
+        let f = field_of[col];
+        if f > 0 {
+            nearby[rng.below(count)][col] = rng.range(upper[f-1]+1..=upper[f]);
+        }
+    }
+    // The invalid tickets have a value above all the ranges.
+    let mut error_rate = 0;
+    for _ in 0..count/4 {
+        let mut t = ticket(rng);
+        let col = rng.below(n);
+        t[col] = rng.range(max+1..=999);
+        error_rate += t[col];
+        nearby.push(t);
+    }
+    rng.shuffle(&mut nearby);
+
+    let mut fields: Vec<String> = (0..n)
+        .map(|i| format!("{}: {}-{} or 30-{}", names[i], rng.range(1..=14), rng.range(15..=29), upper[i]))
+        .collect();
+    rng.shuffle(&mut fields);
+    let csv = |t: &[i64]| t.iter().map(i64::to_string).collect::<Vec<_>>().join(",");
+    let input = format!("{}\nyour ticket:\n{}\n\nnearby tickets:\n{}",
+        lines(fields), csv(&mine), lines(nearby.iter().map(|t| csv(t))));
+    let departures: i64 = (0..n).filter(|&col| names[field_of[col]].starts_with("departure")).map(|col| mine[col]).product();
+    Generated::with_answers(input, error_rate, departures)
+}
+
+fn day17(rng: &mut Rng, scale: f64) -> Generated {
+    let side = scaled_side(8, scale);
+    Generated::new(lines((0..side).map(|_| (0..side).map(|_| if rng.chance(0.45) { '#' } else { '.' }).collect())))
+}
+
+// A random expression of day 18, with its values when + and * have the
+// same precedence, and when + comes first. The values saturate instead
+// of overflowing.
+fn expression(rng: &mut Rng, depth: usize) -> (String, u128, u128) {
+    let mut text = String::new();
+    let (mut value1, mut product, mut sum) = (0u128, 1u128, 0u128);
+    for k in 0..rng.range(2..=if depth == 0 { 6 } else { 4 }) {
+        let (term, v1, v2) = if depth < 2 && rng.chance(0.25) {
+            let (s, v1, v2) = expression(rng, depth + 1);
+            (format!("({})", s), v1, v2)
+        } else {
+            let d = rng.range(1..=9);
+            (d.to_string(), d as u128, d as u128)
+        };
+        if k > 0 && rng.chance(0.5) {
+            text += " * ";
+            value1 = value1.saturating_mul(v1);
+            product = product.saturating_mul(sum);
+            sum = v2;
+        } else {
+            if k > 0 {
+                text += " + ";
+            }
+            value1 = value1.saturating_add(v1);
+            sum = sum.saturating_add(v2);
+        }
+        text += &term;
+    }
+    (text, value1, product.saturating_mul(sum))
+}
+
+fn day18(rng: &mut Rng, scale: f64) -> Generated {
+    const MAX_VALUE: u128 = 100_000_000_000;
+    let (mut sum1, mut sum2) = (0, 0);
+    let mut homework = Vec::new();
+    while homework.len() < scaled(380, scale) {
+        let (text, v1, v2) = expression(rng, 0);
+        if v1 <= MAX_VALUE && v2 <= MAX_VALUE {
+            homework.push(text);
+            sum1 += v1;
+            sum2 += v2;
+        }
+    }
+    Generated::with_answers(lines(homework), sum1, sum2)
+}
+
+// The rules of day 19 under construction: a letter, or alternative
+// sequences of other rules (by index).
+enum RuleBody {
+    Literal(char),
+    Alternatives(Vec<Vec<usize>>),
+}
+
+#[derive(Default)]
+struct Grammar {
+    rules: Vec<RuleBody>,
+    known: HashMap<Vec<String>, usize>,
+}
+
+impl Grammar {
+    // The rule matching exactly a set of strings of the same length
+    // (of 'a' and 'b'), by their first letter and the rest.
+    fn rule(&mut self, mut set: Vec<String>) -> usize {
+        set.sort();
+        if let Some(&r) = self.known.get(&set) {
+            return r;
+        }
+        let body = if set.len() == 1 && set[0].len() == 1 {
+            RuleBody::Literal(set[0].chars().next().unwrap())
+        } else {
+            let mut alternatives = Vec::new();
+            for c in ['a', 'b'] {
+                let rest: Vec<String> = set.iter().filter_map(|s| s.strip_prefix(c)).map(String::from).collect();
+                if rest.is_empty() {
+                    continue;
+                }
+                let first = self.rule(vec![c.to_string()]);
+                if rest[0].is_empty() {
+                    alternatives.push(vec![first]);
+                } else {
+                    alternatives.push(vec![first, self.rule(rest)]);
+                }
+            }
+            RuleBody::Alternatives(alternatives)
+        };
+        self.rules.push(body);
+        self.known.insert(set, self.rules.len() - 1);
+        self.rules.len() - 1
+    }
+}
+
+fn day19(rng: &mut Rng, scale: f64) -> Generated {
+    // Rules 42 and 31 match complementary sets of chunks (the strings of
+    // CHUNK letters), so that the messages made of chunks can be checked
+    // against 42 42 31 (part 1) and 42{k} 31{m} with k > m > 0 (part 2).
+    const CHUNK: usize = 5;
+    let mut chunks: Vec<String> = (0..1 << CHUNK)
+        .map(|x| (0..CHUNK).map(|b| if x >> b & 1 == 0 { 'a' } else { 'b' }).collect())
+        .collect();
+    rng.shuffle(&mut chunks);
+    let (set42, set31) = chunks.split_at(chunks.len() / 2);
+    let mut grammar = Grammar::default();
+    let rule42 = grammar.rule(set42.to_vec());
+    let rule31 = grammar.rule(set31.to_vec());
+
+    // Give the other rules random ids, avoiding the ones with a meaning.
+    let mut free: Vec<usize> = (1..grammar.rules.len() + 10).filter(|id| ![8, 11, 31, 42].contains(id)).collect();
+    rng.shuffle(&mut free);
+    let ids: Vec<usize> = (0..grammar.rules.len())
+        .map(|r| if r == rule42 { 42 } else if r == rule31 { 31 } else { free[r] })
+        .collect();
+    let mut rules = vec!["0: 8 11".to_string(), "8: 42".to_string(), "11: 42 31".to_string()];
+    for (r, body) in grammar.rules.iter().enumerate() {
+        let body = match body {
+            RuleBody::Literal(c) => format!("\"{}\"", c),
+            RuleBody::Alternatives(alternatives) => alternatives.iter()
+                .map(|seq| seq.iter().map(|&r| ids[r].to_string()).collect::<Vec<_>>().join(" "))
+                .collect::<Vec<_>>()
+                .join(" | "),
+        };
+        rules.push(format!("{}: {}", ids[r], body));
+    }
+    rng.shuffle(&mut rules);
+
+    let (mut matches1, mut matches2) = (0, 0);
+    let messages: Vec<String> = (0..scaled(470, scale)).map(|_| {
+        let msg: String = match rng.below(4) {
+            0 => [set42, set42, set31].iter().map(|set| rng.choose(set).as_str()).collect(),
+            1 => {
+                let k = rng.range(2..=5);
+                let m = rng.range(1..=k-1);
+                (0..k+m).map(|i| rng.choose(if i < k { set42 } else { set31 }).as_str()).collect()
+            },
+            2 => (0..rng.range(1..=6)).map(|_| rng.choose(&chunks).as_str()).collect(),
+            _ => (0..rng.range(1..=30)).map(|_| if rng.chance(0.5) { 'a' } else { 'b' }).collect(),
+        };
+        if msg.len().is_multiple_of(CHUNK) {
+            let is42: Vec<bool> = (0..msg.len()).step_by(CHUNK).map(|i| set42.iter().any(|c| *c == msg[i..i+CHUNK])).collect();
+            let k = is42.iter().take_while(|&&b| b).count();
+            let m = is42.len() - k;
+            matches1 += (is42 == [true, true, false]) as usize;
+            matches2 += (is42[k..].iter().all(|&b| !b) && k > m && m > 0) as usize;
+        }
+        msg
+    }).collect();
+    Generated::with_answers(format!("{}\n{}", lines(rules), lines(messages)), matches1, matches2)
+}
+
+const SEA_MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+
+// The cells of the sea monster in the 8 orientations, the first one as drawn.
+fn sea_monsters() -> Vec<Vec<(i64, i64)>> {
+    let cells: Vec<(i64, i64)> = SEA_MONSTER.iter().enumerate()
+        .flat_map(|(y, row)| row.char_indices().filter(|&(_, c)| c == '#').map(move |(x, _)| (y as i64, x as i64)))
+        .collect();
+    let mut orientations = Vec::new();
+    for flip in [false, true] {
+        let mut cells: Vec<_> = cells.iter().map(|&(y, x)| if flip { (x, y) } else { (y, x) }).collect();
+        for _ in 0..4 {
+            let (min_y, min_x) = (cells.iter().map(|c| c.0).min().unwrap(), cells.iter().map(|c| c.1).min().unwrap());
+            orientations.push(cells.iter().map(|&(y, x)| (y - min_y, x - min_x)).collect());
+            cells = cells.iter().map(|&(y, x)| (x, -y)).collect();
+        }
+    }
+    orientations
+}
+
+fn day20(rng: &mut Rng, scale: f64) -> Generated {
+    // An image of n x n tiles, which share their borders with their
+    // neighbours. The borders are drawn again until none is symmetric and
+    // no two look the same (even when flipped), so that the arrangement is
+    // unique; larger images need larger tiles for that. The sea monsters
+    // are drawn on a sparse background, which is drawn again if it has any
+    // other monster (in any orientation).
+    let n = scaled_side(12, scale).clamp(3, 90);
+    let edges = 2 * n * (n + 1);
+    let size = if edges <= 312 { 10 } else { 3 + edges.next_power_of_two().trailing_zeros() as usize };
+    let (step, inner) = (size - 1, size - 2);
+    let side = n * step + 1;
+    let image_side = n * inner;
+    let monsters = sea_monsters();
+
+    let (pixels, placed) = loop {
+        let mut pixels: Vec<Vec<bool>> = (0..side)
+            .map(|r| (0..side).map(|c| rng.chance(if r % step == 0 || c % step == 0 { 0.5 } else { 0.3 })).collect())
+            .collect();
+
+        let mut seen = HashSet::new();
+        for (i, j) in (0..=n).flat_map(|i| (0..n).map(move |j| (i, j))) {
+            for horizontal in [true, false] {
+                let cell = |k| if horizontal { (i * step, j * step + k) } else { (j * step + k, i * step) };
+                loop {
+                    for k in 1..step {
+                        let (r, c) = cell(k);
+                        pixels[r][c] = rng.chance(0.5);
+                    }
+                    let border: Vec<bool> = (0..size).map(|k| { let (r, c) = cell(k); pixels[r][c] }).collect();
+                    let reversed: Vec<bool> = border.iter().rev().copied().collect();
+                    if border != reversed && !seen.contains(&border) {
+                        seen.insert(reversed);
+                        seen.insert(border);
+                        break;
+                    }
+                }
+            }
+        }
+
+        let at = |y: usize, x: usize| (y / inner * step + 1 + y % inner, x / inner * step + 1 + x % inner);
+        let mut placed: Vec<(usize, usize)> = Vec::new();
+        for _ in 0..image_side * image_side / 10 {
+            if placed.len() >= image_side * image_side / 300 {
+                break;
+            }
+            let (y, x) = (rng.below(image_side - 2), rng.below(image_side - 19));
+            if placed.iter().all(|&(py, px)| py.abs_diff(y) >= 3 || px.abs_diff(x) >= 20) {
+                placed.push((y, x));
+                for &(dy, dx) in &monsters[0] {
+                    let (r, c) = at(y + dy as usize, x + dx as usize);
+                    pixels[r][c] = true;
+                }
+            }
+        }
+
+        let counts: Vec<usize> = monsters.iter().map(|cells| {
+            let (height, width) = (cells.iter().map(|c| c.0).max().unwrap() as usize + 1, cells.iter().map(|c| c.1).max().unwrap() as usize + 1);
+            (0..=image_side - height).flat_map(|y| (0..=image_side - width).map(move |x| (y, x)))
+                .filter(|&(y, x)| cells.iter().all(|&(dy, dx)| { let (r, c) = at(y + dy as usize, x + dx as usize); pixels[r][c] }))
+                .count()
+        }).collect();
+        if counts[0] == placed.len() && counts[1..].iter().all(|&c| c == 0) {
+            break (pixels, placed.len());
+        }
+    };
+
+    let ids: Vec<usize> = rng.sample(9000, n * n).into_iter().map(|id| id + 1000).collect();
+    let mut tiles: Vec<String> = (0..n * n).map(|t| {
+        let (r0, c0) = (t / n * step, t % n * step);
+        let mut tile: Vec<Vec<bool>> = (0..size).map(|r| pixels[r0 + r][c0..c0 + size].to_vec()).collect();
+        for _ in 0..rng.below(4) {
+            tile = (0..size).map(|r| (0..size).map(|c| tile[size - 1 - c][r]).collect()).collect();
+        }
+        if rng.chance(0.5) {
+            tile = (0..size).map(|r| (0..size).map(|c| tile[c][r]).collect()).collect();
+        }
+        let rows: Vec<String> = tile.iter().map(|row| row.iter().map(|&p| if p { '#' } else { '.' }).collect()).collect();
+        format!("Tile {}:\n{}", ids[t], lines(rows))
+    }).collect();
+    rng.shuffle(&mut tiles);
+
+    let corners: usize = [0, n - 1, n * (n - 1), n * n - 1].iter().map(|&t| ids[t]).product();
+    let roughness = (0..side)
+        .flat_map(|r| (0..side).map(move |c| (r, c)))
+        .filter(|&(r, c)| r % step != 0 && c % step != 0 && pixels[r][c])
+        .count() - placed * monsters[0].len();
+    Generated::with_answers(tiles.join("\n"), corners, roughness)
+}
+
+const ALLERGENS: [&str; 9] = ["dairy", "eggs", "fish", "nuts", "peanuts", "sesame", "shellfish", "soy", "wheat"];
+
+fn day21(rng: &mut Rng, scale: f64) -> Generated {
+    // Each allergen is in one ingredient. The foods are random otherwise,
+    // and drawn again until the ingredients common to the foods listing
+    // each allergen are all dangerous, and part 2 can tell them apart.
+    let n_ingredients = scaled(200, scale).max(10);
+    let n_foods = scaled(40, scale).max(3);
+    let n_allergens = scaled(8, scale).clamp(1, ALLERGENS.len());
+    let mut names = HashSet::new();
+    while names.len() < n_ingredients {
+        names.insert((0..rng.range(4..=8)).map(|_| letter(rng)).collect::<String>());
+    }
+    let names: Vec<String> = names.into_iter().collect();
+
+    loop {
+        let mut allergens: Vec<&str> = rng.sample(ALLERGENS.len(), n_allergens).into_iter().map(|a| ALLERGENS[a]).collect();
+        allergens.sort();
+        // The ingredient of each allergen.
+        let dangerous = rng.sample(n_ingredients, n_allergens);
+        let foods: Vec<(HashSet<usize>, Vec<usize>)> = (0..n_foods).map(|_| {
+            let k = rng.range(1..=n_allergens.min(3) as i64) as usize;
+            let listed = rng.sample(n_allergens, k);
+            let ingredients = (0..n_ingredients)
+                .filter(|i| listed.iter().any(|&a| dangerous[a] == *i) || rng.chance(0.3))
+                .collect();
+            (ingredients, listed)
+        }).collect();
+
+        let mut candidates: Vec<Option<HashSet<usize>>> = vec![None; n_allergens];
+        for (ingredients, listed) in &foods {
+            for &a in listed {
+                candidates[a] = Some(match &candidates[a] {
+                    Some(c) => c.intersection(ingredients).copied().collect(),
+                    None => ingredients.clone(),
+                });
+            }
+        }
+        let Some(mut candidates) = candidates.into_iter().collect::<Option<Vec<_>>>() else {
+            continue;
+        };
+        if candidates.iter().flatten().any(|i| !dangerous.contains(i)) {
+            continue;
+        }
+        while let Some(a) = candidates.iter().position(|c| c.len() == 1) {
+            let i = *candidates[a].iter().next().unwrap();
+            candidates.iter_mut().for_each(|c| { c.remove(&i); });
+        }
+        if candidates.iter().any(|c| !c.is_empty()) {
+            continue;
+        }
+
+        let safe = foods.iter().map(|(ingredients, _)| ingredients.iter().filter(|i| !dangerous.contains(i)).count()).sum::<usize>();
+        let list = dangerous.iter().map(|&i| names[i].as_str()).collect::<Vec<_>>().join(",");
+        let lines = lines(foods.into_iter().map(|(ingredients, listed)| {
+            let mut ingredients: Vec<&str> = ingredients.into_iter().map(|i| names[i].as_str()).collect();
+            rng.shuffle(&mut ingredients);
+            let listed: Vec<&str> = listed.into_iter().map(|a| allergens[a]).collect();
+            format!("{} (contains {})", ingredients.join(" "), listed.join(", "))
+        }));
+        return Generated::with_answers(lines, safe, list);
+    }
+}
+
+fn day22(rng: &mut Rng, scale: f64) -> Generated {
+    // The recursive game gets slow very quickly with larger decks (up to
+    // minutes with 40 cards each).
+    let n = scaled(25, scale).clamp(2, 35);
+    let cards: Vec<String> = rng.sample(2 * n, 2 * n).into_iter().map(|c| (c + 1).to_string()).collect();
+    Generated::new(format!("Player 1:\n{}\nPlayer 2:\n{}", lines(cards[..n].to_vec()), lines(cards[n..].to_vec())))
+}
+
+fn day23(rng: &mut Rng, _: f64) -> Generated {
+    Generated::new(rng.sample(9, 9).into_iter().map(|c| (c + 1).to_string()).collect::<String>() + "\n")
+}
+
+fn day24(rng: &mut Rng, scale: f64) -> Generated {
+    Generated::new(lines((0..scaled(350, scale)).map(|_| {
+        (0..rng.range(15..=25)).map(|_| *rng.choose(&["e", "se", "sw", "w", "nw", "ne"])).collect()
+    })))
+}
+
+fn day25(rng: &mut Rng, scale: f64) -> Generated {
+    // Part 1 finds a loop size by brute force, so the loop sizes scale.
+    const P: u64 = 20201227;
+    let pow = |base: u64, mut exp: u64| {
+        let (mut result, mut base) = (1, base);
+        while exp > 0 {
+            if exp % 2 == 1 {
+                result = result * base % P;
+            }
+            base = base * base % P;
+            exp /= 2;
+        }
+        result
+    };
+    let max_loops = scaled(20_000_000, scale).clamp(2, P as usize - 2) as i64;
+    let (card_loops, door_loops) = (rng.range(1..=max_loops) as u64, rng.range(1..=max_loops) as u64);
+    let card_key = pow(7, card_loops);
+    Generated{
+        part1: Some(pow(card_key, door_loops).to_string()),
+        ..Generated::new(format!("{}\n{}\n", card_key, pow(7, door_loops)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use crate::params::Params;
+
+    // Smaller parameters for the days that would be slow in debug builds.
+    const PARAMS: [(u32, &str, &str); 3] = [(15, "turns2", "2020"), (23, "cups2", "100"), (23, "moves2", "1000")];
+
+    #[test]
+    fn all_days() {
+        for day in days::DAYS {
+            let s = days::get(day).unwrap();
+            let mut params = Params::new(s.params());
+            for (_, name, value) in PARAMS.iter().filter(|p| p.0 == day) {
+                params.set(name, value).unwrap();
+            }
+            for seed in 0..3 {
+                let g = generate(day, 0.1, &mut Rng::new(seed)).unwrap();
+                let input = s.parse_input(&g.input, &params)
+                    .unwrap_or_else(|e| panic!("day {} (seed {}): {}", day, seed, e));
+                let answers = [(s.run_part1(input.as_ref()), g.part1), (s.run_part2(input.as_ref()), g.part2)];
+                for (part, (answer, expected)) in answers.into_iter().enumerate() {
+                    if day == 25 && part == 1 {
+                        continue; // there is no part 2 on the last day
+                    }
+                    assert!(answer.is_solved(), "day {} part {} (seed {}) is unsolved", day, part + 1, seed);
+                    if let Some(expected) = expected {
+                        assert_eq!(answer.value().to_string(), expected, "day {} part {} (seed {})", day, part + 1, seed);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn seeds_and_scales() {
+        let input = |day, scale, seed| generate(day, scale, &mut Rng::new(seed)).unwrap().input;
+        assert_eq!(input(7, 0.5, 1), input(7, 0.5, 1));
+        assert_ne!(input(7, 0.5, 1), input(7, 0.5, 2));
+        assert_eq!(input(12, 2.0, 3).lines().count(), 2 * input(12, 1.0, 3).lines().count());
+        assert!(generate(26, 1.0, &mut Rng::new(0)).is_none());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::error::ParseError;
 use std::fmt::Display;
 
 pub mod consts {
@@ -11,6 +12,11 @@ pub mod consts {
     pub const UP_RIGHT: Direction = Direction{ dx: 1, dy: -1 };
     pub const DOWN_LEFT: Direction = Direction{ dx: -1, dy: 1 };
     pub const DOWN_RIGHT: Direction = Direction{ dx: 1, dy: 1 };
+
+    // The neighbourhoods of a cell, clockwise from up: the 4 orthogonal
+    // neighbours (von Neumann), and the 8 including the diagonal ones (Moore).
+    pub const NEIGHBOURS_4: [Direction; 4] = [UP, RIGHT, DOWN, LEFT];
+    pub const NEIGHBOURS_8: [Direction; 8] = [UP, UP_RIGHT, RIGHT, DOWN_RIGHT, DOWN, DOWN_LEFT, LEFT, UP_LEFT];
 }
 
 #[derive(Clone, Copy, Debug, Hash, PartialOrd, Ord, PartialEq, Eq)]
@@ -24,16 +30,21 @@ impl Location {
         Location{ x: self.x + dir.dx, y: self.y + dir.dy }
     }
 
-    pub fn go_until<F>(&self, dir: &Direction, f: F) -> Location where
-        F: Fn(&Location) -> bool {
-        (1..).find_map(|n| {
-            let pos = self.go(&dir.times(n));
-            if f(&pos) {
-                Some(pos)
-            } else {
-                None
-            }
-        }).unwrap()
+    // The locations met going from this one (excluded) in direction 'dir',
+    // until the coordinates would overflow. The ray of the zero direction
+    // is empty rather than repeating the location.
+    pub fn ray(&self, dir: &Direction) -> impl Iterator<Item = Location> {
+        let dir = *dir;
+        let start = (dir.dx != 0 || dir.dy != 0).then_some(*self);
+        std::iter::successors(start, move |l| Some(Location{ x: l.x.checked_add(dir.dx)?, y: l.y.checked_add(dir.dy)? }))
+            .skip(1)
+    }
+
+    // The locations next to this one in the given directions, such as
+    // consts::NEIGHBOURS_8.
+    pub fn neighbours<'a>(&self, dirs: &'a [Direction]) -> impl Iterator<Item = Location> + 'a {
+        let l = *self;
+        dirs.iter().map(move |d| l.go(d))
     }
 
     pub fn distance(&self, other: &Location) -> i64 {
@@ -69,32 +80,71 @@ impl Direction {
     }
 }
 
-// A fixed-size grid of bytes, with values accessed by row/column
-// with get()/set(), or by Location with get_by_location().
-#[derive(Clone, Debug)]
-pub struct SimpleGrid {
+// How locations outside of a grid are seen: either as outside, or as
+// the grid repeating itself infinitely to the left and right, or in all
+// directions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Topology {
+    #[default]
+    Bounded,
+    WrapHorizontal,
+    Torus,
+}
+
+// A fixed-size grid of values, accessed by row/column with get()/set(),
+// or by Location with get_by_location(), which follows the topology of
+// the grid (bounded unless set with with_topology()).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
     rows: usize,
     cols: usize,
-    data: Vec<u8>,
+    data: Vec<T>,
+    topology: Topology,
+}
+
+// A grid of bytes, such as the characters of a puzzle input.
+pub type SimpleGrid = Grid<u8>;
+
+impl<T: Clone + Default> Grid<T> {
+    pub fn new(rows: usize, cols: usize) -> Grid<T> {
+        Grid{ rows, cols, data: vec![T::default(); rows*cols], topology: Topology::Bounded }
+    }
 }
 
 impl SimpleGrid {
-    pub fn new(rows: usize, cols: usize) -> SimpleGrid {
-        SimpleGrid{ rows, cols, data: vec![0; rows*cols] }
+    pub fn create_from(s: &str) -> Result<SimpleGrid, ParseError> {
+        Grid::parse(s, Ok::<u8, String>)
     }
+}
 
-    pub fn create_from(s: &str) -> SimpleGrid {
-        let cols = s.find('\n').unwrap();
+impl<T> Grid<T> {
+    // Reads a grid from lines of the same length, converting each byte
+    // with 'f', whose errors are located at the offending byte.
+    pub fn parse<E: Display>(s: &str, f: impl Fn(u8) -> Result<T, E>) -> Result<Grid<T>, ParseError> {
+        let cols = s.lines().next().map_or(0, |line| line.len());
         let mut rows = 0;
         let mut data = Vec::with_capacity(s.len());
 
         for line in s.lines() {
+            if line.len() != cols {
+                return Err(ParseError::new("input lines have different lengths", line));
+            }
             rows += 1;
-            data.extend_from_slice(line.as_bytes());
-            assert_eq!(data.len(), rows*cols, "input lines have different lengths");
+            for (col, b) in line.bytes().enumerate() {
+                let value = f(b).map_err(|e| {
+                    let start = (0..=col).rev().find(|&i| line.is_char_boundary(i)).unwrap_or(0);
+                    ParseError::new(e.to_string(), &line[start..])
+                })?;
+                data.push(value);
+            }
         }
 
-        SimpleGrid{ rows, cols, data }
+        Ok(Grid{ rows, cols, data, topology: Topology::Bounded })
+    }
+
+    // Converts the values with 'f', called in reading order.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid{ rows: self.rows, cols: self.cols, data: self.data.iter().map(f).collect(), topology: self.topology }
     }
 
     pub fn rows(&self) -> usize {
@@ -105,7 +155,7 @@ impl SimpleGrid {
         self.cols
     }
 
-    pub fn get(&self, row: usize, col: usize) -> Option<&u8> {
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
         if row < self.rows && col < self.cols {
             self.data.get(row * self.cols + col)
         } else {
@@ -113,43 +163,365 @@ impl SimpleGrid {
         }
     }
 
-    pub fn set(&mut self, row: usize, col: usize, v: u8) {
+    pub fn set(&mut self, row: usize, col: usize, v: T) {
         assert!(row < self.rows && col < self.cols);
         self.data[row * self.cols + col] = v;
     }
 
-    pub fn entries(&self) -> impl Iterator<Item = ((usize, usize), &u8)> {
-        (0..self.rows).flat_map(move |row| {
-            (0..self.cols).filter_map(move |col| {
-                self.data.get(row * self.cols + col).map(|c| ((row, col), c))
-            })
-        })
+    pub fn entries(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.data.iter().enumerate().map(|(i, v)| ((i / self.cols, i % self.cols), v))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+
+    pub fn with_topology(self, topology: Topology) -> Grid<T> {
+        Grid{ topology, ..self }
+    }
+
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    // The location in the grid which 'l' stands for given the topology, or
+    // None if there is none.
+    pub fn wrap(&self, l: &Location) -> Option<Location> {
+        if self.data.is_empty() {
+            return None;
+        }
+        let (rows, cols) = (self.rows as i64, self.cols as i64);
+        let l = match self.topology {
+            Topology::Bounded => *l,
+            Topology::WrapHorizontal => Location{ x: l.x.rem_euclid(cols), y: l.y },
+            Topology::Torus => Location{ x: l.x.rem_euclid(cols), y: l.y.rem_euclid(rows) },
+        };
+        (l.x >= 0 && l.x < cols && l.y >= 0 && l.y < rows).then_some(l)
+    }
+
+    pub fn get_by_location(&self, l: &Location) -> Option<&T> {
+        self.wrap(l).map(|l| &self.data[l.y as usize * self.cols + l.x as usize])
+    }
+
+    // The cells met going from 'from' (excluded) in direction 'dir', as
+    // long as they are in the grid as wrapped by its topology. Each cell is
+    // met at most once: around a wrapping grid, the ray stops before coming
+    // back to its start.
+    pub fn ray<'a>(&'a self, from: &Location, dir: &Direction) -> impl Iterator<Item = (Location, &'a T)> + 'a {
+        let start = self.wrap(from);
+        from.ray(dir)
+            .map_while(move |l| self.wrap(&l))
+            .take_while(move |l| Some(*l) != start)
+            .map(move |l| (l, self.get_by_location(&l).unwrap()))
+    }
+
+    // The first cell of the ray from 'from' in direction 'dir' whose value
+    // satisfies 'f', or None if the ray leaves the grid before.
+    pub fn first_where(&self, from: &Location, dir: &Direction, f: impl Fn(&T) -> bool) -> Option<Location> {
+        self.ray(from, dir).find(|(_, v)| f(v)).map(|(l, _)| l)
+    }
+
+    // The neighbours of a location in the given directions which are in
+    // the grid, as wrapped by its topology.
+    pub fn neighbours<'a>(&'a self, l: &Location, dirs: &'a [Direction]) -> impl Iterator<Item = Location> + 'a {
+        l.neighbours(dirs).filter_map(|n| self.wrap(&n))
+    }
+
+    pub fn entries_by_location(&self) -> impl Iterator<Item = (Location, &T)> {
+        self.entries().map(|((row, col), v)| (Location{ x: col as i64, y: row as i64 }, v))
+    }
+}
+
+// The eight symmetries of a grid (the dihedral group D4): the rotations
+// by quarter turns clockwise, and the flips along the horizontal, the
+// vertical and the two diagonal axes.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Orientation {
+    Identity,
+    RotateRight,
+    RotateHalf,
+    RotateLeft,
+    FlipHorizontal, // mirrors left and right
+    AntiTranspose,  // mirrors along the diagonal from top right to bottom left
+    FlipVertical,   // mirrors top and bottom
+    Transpose,      // mirrors along the diagonal from top left to bottom right
+}
+
+impl Orientation {
+    pub const ALL: [Orientation; 8] = [
+        Orientation::Identity, Orientation::RotateRight, Orientation::RotateHalf, Orientation::RotateLeft,
+        Orientation::FlipHorizontal, Orientation::AntiTranspose, Orientation::FlipVertical, Orientation::Transpose,
+    ];
+
+    pub fn all() -> impl Iterator<Item = Orientation> {
+        Orientation::ALL.into_iter()
+    }
+
+    // Each orientation is a horizontal flip (or not) followed by a number
+    // of quarter turns clockwise.
+    fn flip_turns(self) -> (bool, usize) {
+        let i = self as usize;
+        (i >= 4, i % 4)
+    }
+
+    fn from_flip_turns(flip: bool, turns: usize) -> Orientation {
+        Orientation::ALL[flip as usize * 4 + turns % 4]
     }
 
-    pub fn values(&self) -> impl Iterator<Item = &u8> {
-        (0..self.rows).flat_map(move |row| {
-            (0..self.cols).filter_map(move |col| {
-                self.data.get(row * self.cols + col)
-            })
-        })
+    // The orientation obtained by applying self, then other.
+    pub fn then(self, other: Orientation) -> Orientation {
+        let (f1, t1) = self.flip_turns();
+        let (f2, t2) = other.flip_turns();
+        // A flip followed by a turn is the opposite turn followed by a flip.
+        let t1 = if f2 { 4 - t1 } else { t1 };
+        Orientation::from_flip_turns(f1 != f2, t1 + t2)
     }
 
-    pub fn get_by_location(&self, l: &Location) -> Option<&u8> {
-        if l.x >= 0 && (l.x as usize) < self.cols && l.y >= 0 && (l.y as usize) < self.rows {
-            self.data.get(l.y as usize * self.cols + l.x as usize)
+    pub fn inverse(self) -> Orientation {
+        match self.flip_turns() {
+            (false, turns) => Orientation::from_flip_turns(false, 4 - turns),
+            (true, _) => self,
+        }
+    }
+
+    // Where the cell at (row, col) of a grid of the given size goes, with
+    // the size of the transformed grid.
+    fn apply(self, (row, col): (i64, i64), (rows, cols): (i64, i64)) -> ((i64, i64), (i64, i64)) {
+        let (flip, turns) = self.flip_turns();
+        let (mut pos, mut size) = ((row, if flip { cols - 1 - col } else { col }), (rows, cols));
+        for _ in 0..turns {
+            pos = (pos.1, size.0 - 1 - pos.0);
+            size = (size.1, size.0);
+        }
+        (pos, size)
+    }
+}
+
+// A transformed grid, which reads the cells of the original one.
+#[derive(Clone, Copy, Debug)]
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    orientation: Orientation,
+    rows: usize,
+    cols: usize,
+    // The cell (row, col) of the view is the cell origin + row*dr + col*dc
+    // of the grid.
+    origin: (i64, i64),
+    dr: (i64, i64),
+    dc: (i64, i64),
+}
+
+impl<'a, T> View<'a, T> {
+    fn new(grid: &'a Grid<T>, orientation: Orientation) -> View<'a, T> {
+        let (_, (rows, cols)) = orientation.apply((0, 0), (grid.rows as i64, grid.cols as i64));
+        let inverse = orientation.inverse();
+        let source = |pos| inverse.apply(pos, (rows, cols)).0;
+        let origin = source((0, 0));
+        let (r, c) = (source((1, 0)), source((0, 1)));
+        View{
+            grid, orientation,
+            rows: rows as usize,
+            cols: cols as usize,
+            origin,
+            dr: (r.0 - origin.0, r.1 - origin.1),
+            dc: (c.0 - origin.0, c.1 - origin.1),
+        }
+    }
+
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&'a T> {
+        if row < self.rows && col < self.cols {
+            let (row, col) = (row as i64, col as i64);
+            let r = self.origin.0 + row * self.dr.0 + col * self.dc.0;
+            let c = self.origin.1 + row * self.dr.1 + col * self.dc.1;
+            self.grid.get(r as usize, c as usize)
         } else {
             None
         }
     }
 
-    pub fn entries_by_location(&self) -> impl Iterator<Item = (Location, &u8)> {
-        (0..self.rows).flat_map(move |row| {
-            (0..self.cols).filter_map(move |col| {
-                self.data.get(row * self.cols + col).map(|c| {
-                    (Location{ x: col as i64, y: row as i64 }, c)
-                })
-            })
-        })
+    pub fn entries(&self) -> impl Iterator<Item = ((usize, usize), &'a T)> + '_ {
+        (0..self.rows).flat_map(move |row| (0..self.cols).map(move |col| ((row, col), self.get(row, col).unwrap())))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &'a T> + '_ {
+        self.entries().map(|(_, v)| v)
+    }
+
+    // A copy of the view, as a bounded grid.
+    pub fn to_grid(&self) -> Grid<T> where T: Clone {
+        Grid{ rows: self.rows, cols: self.cols, data: self.values().cloned().collect(), topology: Topology::Bounded }
+    }
+}
+
+impl<T> Grid<T> {
+    // The grid transformed, without copying it.
+    pub fn view(&self, orientation: Orientation) -> View<'_, T> {
+        View::new(self, orientation)
+    }
+
+    // The grid in its eight orientations, without copying it.
+    pub fn orientations(&self) -> impl Iterator<Item = View<'_, T>> {
+        Orientation::all().map(|o| self.view(o))
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    // A transformed copy of the grid.
+    pub fn transform(&self, orientation: Orientation) -> Grid<T> {
+        self.view(orientation).to_grid()
+    }
+
+    pub fn rotate_right(&self) -> Grid<T> {
+        self.transform(Orientation::RotateRight)
+    }
+
+    pub fn rotate_left(&self) -> Grid<T> {
+        self.transform(Orientation::RotateLeft)
+    }
+
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        self.transform(Orientation::FlipHorizontal)
+    }
+
+    pub fn flip_vertical(&self) -> Grid<T> {
+        self.transform(Orientation::FlipVertical)
+    }
+
+    pub fn transpose(&self) -> Grid<T> {
+        self.transform(Orientation::Transpose)
+    }
+}
+
+// A pattern to search in grids, whose cells equal to the wildcard (if any)
+// match any value. It is searched as is, or in all its orientations.
+#[derive(Clone, Debug)]
+pub struct Pattern<T> {
+    shapes: Vec<Shape<T>>,
+}
+
+// One orientation of a pattern, with the cells which must match.
+#[derive(Clone, Debug)]
+struct Shape<T> {
+    orientation: Orientation,
+    rows: usize,
+    cols: usize,
+    cells: Vec<((usize, usize), T)>,
+}
+
+// An occurrence of a pattern: the pattern transformed by 'orientation'
+// has its top left corner at (row, col) of the grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Match {
+    pub row: usize,
+    pub col: usize,
+    pub orientation: Orientation,
+}
+
+impl<T: Clone + PartialEq> Pattern<T> {
+    pub fn new(grid: &Grid<T>, wildcard: Option<T>) -> Pattern<T> {
+        Pattern{ shapes: vec![Shape::new(grid.view(Orientation::Identity), &wildcard)] }
+    }
+
+    // The pattern searched in its eight orientations, except those giving
+    // the same shape as another one, so that a symmetric pattern is only
+    // found once at each place.
+    pub fn in_all_orientations(grid: &Grid<T>, wildcard: Option<T>) -> Pattern<T> {
+        let mut shapes: Vec<Shape<T>> = Vec::new();
+        for view in grid.orientations() {
+            let shape = Shape::new(view, &wildcard);
+            if !shapes.iter().any(|s| s.same_as(&shape)) {
+                shapes.push(shape);
+            }
+        }
+        Pattern{ shapes }
+    }
+
+    fn shape(&self, orientation: Orientation) -> &Shape<T> {
+        self.shapes.iter().find(|s| s.orientation == orientation).expect("orientation not searched")
+    }
+
+    // The grid cells covered by the non-wildcard cells of a match, with
+    // the values of the pattern.
+    pub fn cells(&self, m: &Match) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let (row, col) = (m.row, m.col);
+        self.shape(m.orientation).cells.iter().map(move |((r, c), v)| ((row + r, col + c), v))
+    }
+}
+
+impl<T: Clone + PartialEq> Shape<T> {
+    fn new(view: View<T>, wildcard: &Option<T>) -> Shape<T> {
+        let cells = view.entries()
+            .filter(|(_, v)| wildcard.as_ref() != Some(*v))
+            .map(|(pos, v)| (pos, v.clone()))
+            .collect();
+        Shape{ orientation: view.orientation(), rows: view.rows(), cols: view.cols(), cells }
+    }
+
+    fn same_as(&self, other: &Shape<T>) -> bool {
+        (self.rows, self.cols) == (other.rows, other.cols) && self.cells == other.cells
+    }
+
+    fn matches_at(&self, grid: &Grid<T>, row: usize, col: usize) -> bool {
+        self.cells.iter().all(|((r, c), v)| grid.get(row + r, col + c) == Some(v))
+    }
+}
+
+impl<T: Clone + PartialEq> Grid<T> {
+    // All the occurrences of a pattern, by orientation (in the order of
+    // Orientation::ALL) then in reading order. They may overlap.
+    pub fn find(&self, pattern: &Pattern<T>) -> Vec<Match> {
+        let mut matches = Vec::new();
+        for shape in &pattern.shapes {
+            for row in 0..(self.rows + 1).saturating_sub(shape.rows) {
+                for col in 0..(self.cols + 1).saturating_sub(shape.cols) {
+                    if shape.matches_at(self, row, col) {
+                        matches.push(Match{ row, col, orientation: shape.orientation });
+                    }
+                }
+            }
+        }
+        matches
+    }
+
+    // The occurrences of a pattern which do not share a non-wildcard cell,
+    // chosen greedily in the order of find().
+    pub fn find_disjoint(&self, pattern: &Pattern<T>) -> Vec<Match> {
+        let mut used = Grid::<bool>::new(self.rows, self.cols);
+        let mut matches = self.find(pattern);
+        matches.retain(|m| {
+            let free = pattern.cells(m).all(|((row, col), _)| !used.get(row, col).unwrap());
+            if free {
+                pattern.cells(m).for_each(|((row, col), _)| used.set(row, col, true));
+            }
+            free
+        });
+        matches
+    }
+
+    // Sets the cells covered by the non-wildcard cells of a match to 'value'.
+    pub fn stamp(&mut self, pattern: &Pattern<T>, m: &Match, value: T) {
+        for ((row, col), _) in pattern.cells(m) {
+            self.set(row, col, value.clone());
+        }
+    }
+
+    // Copies the non-wildcard cells of a match from the pattern.
+    pub fn overlay(&mut self, pattern: &Pattern<T>, m: &Match) {
+        for ((row, col), v) in pattern.cells(m) {
+            self.set(row, col, v.clone());
+        }
     }
 }
 
@@ -165,3 +537,177 @@ impl Display for SimpleGrid {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    enum Cell { #[default] Open, Wall }
+
+    #[test]
+    fn generic() {
+        let cell = |b| match b {
+            b'.' => Ok(Cell::Open),
+            b'#' => Ok(Cell::Wall),
+            _ => Err("expected '.' or '#'"),
+        };
+        let mut g = Grid::parse("..#\n#..\n", cell).unwrap();
+        assert_eq!((g.rows(), g.cols()), (2, 3));
+        assert_eq!(g.get(0, 2), Some(&Cell::Wall));
+        assert_eq!(g.get(2, 0), None);
+        assert_eq!(g.get_by_location(&Location{ x: 0, y: 1 }), Some(&Cell::Wall));
+        assert_eq!(g.get_by_location(&Location{ x: -1, y: 1 }), None);
+        g.set(1, 1, Cell::Wall);
+        let walls: Vec<_> = g.entries().filter(|(_, c)| **c == Cell::Wall).map(|(pos, _)| pos).collect();
+        assert_eq!(walls, vec![(0, 2), (1, 0), (1, 1)]);
+
+        let mut n = 0;
+        let numbers = g.map(|c| (*c == Cell::Open).then(|| { n += 1; n }));
+        assert_eq!(numbers.values().copied().collect::<Vec<_>>(), vec![Some(1), Some(2), None, None, None, Some(3)]);
+        assert_eq!(Grid::<Cell>::new(2, 2).values().filter(|c| **c == Cell::Open).count(), 4);
+
+        let e = Grid::parse("..#\n.é\n", cell).unwrap_err();
+        assert_eq!(e.to_string(), "expected '.' or '#' at 'é'");
+        assert!(Grid::parse("..#\n#.\n", cell).is_err());
+    }
+
+    #[test]
+    fn topologies() {
+        use consts::*;
+        let g = SimpleGrid::create_from("abc\ndef").unwrap();
+        let at = |g: &SimpleGrid, x, y| g.get_by_location(&Location{ x, y }).copied();
+        let around = |g: &SimpleGrid, x, y| g.neighbours(&Location{ x, y }, &NEIGHBOURS_8).map(|l| *g.get_by_location(&l).unwrap()).collect::<Vec<_>>();
+        assert_eq!(g.topology(), Topology::Bounded);
+        assert_eq!((at(&g, 2, 1), at(&g, 3, 0), at(&g, -1, 0)), (Some(b'f'), None, None));
+        assert_eq!(around(&g, 0, 0), b"bed");
+        assert_eq!(g.neighbours(&ORIGIN, &NEIGHBOURS_4).collect::<Vec<_>>(), vec![Location{ x: 1, y: 0 }, Location{ x: 0, y: 1 }]);
+
+        let h = g.clone().with_topology(Topology::WrapHorizontal);
+        assert_eq!((at(&h, 3, 0), at(&h, -1, 1), at(&h, 301, 1), at(&h, 0, 2)), (Some(b'a'), Some(b'f'), Some(b'e'), None));
+        assert_eq!(around(&h, 0, 0), b"bedfc");
+        assert_eq!(h.wrap(&Location{ x: -4, y: 1 }), Some(Location{ x: 2, y: 1 }));
+
+        let t = g.clone().with_topology(Topology::Torus);
+        assert_eq!((at(&t, 0, 2), at(&t, -1, -1), at(&t, 5, 7)), (Some(b'a'), Some(b'f'), Some(b'f')));
+        assert_eq!(around(&t, 0, 0), b"debedfcf");
+        assert_eq!(t.map(|c| c.to_ascii_uppercase()).topology(), Topology::Torus);
+        assert_eq!(t.transpose().topology(), Topology::Bounded);
+        assert_eq!(SimpleGrid::new(0, 0).with_topology(Topology::Torus).wrap(&ORIGIN), None);
+    }
+
+    #[test]
+    fn rays() {
+        use consts::*;
+        let l = |x, y| Location{ x, y };
+        assert_eq!(ORIGIN.ray(&DOWN_RIGHT).take(3).collect::<Vec<_>>(), vec![l(1, 1), l(2, 2), l(3, 3)]);
+        assert_eq!(ORIGIN.ray(&Direction{ dx: 0, dy: 0 }).next(), None);
+        assert_eq!(l(i64::MAX - 3, 0).ray(&RIGHT.times(2)).collect::<Vec<_>>(), vec![l(i64::MAX - 1, 0)]);
+
+        let g = SimpleGrid::create_from("a.b.\n..c.\nd...").unwrap();
+        let cells = |g: &SimpleGrid, from, dir| g.ray(&from, &dir).map(|(_, v)| *v).collect::<Vec<_>>();
+        assert_eq!(cells(&g, l(0, 0), RIGHT), b".b.");
+        assert_eq!(cells(&g, l(0, 0), DOWN_RIGHT), b"..");
+        assert_eq!(cells(&g, l(-1, 0), RIGHT), b"a.b.");
+        assert_eq!(cells(&g, l(-2, 0), RIGHT), b"");
+        assert_eq!(cells(&g, l(2, 2), UP_LEFT.times(2)), b"a");
+        let seat = |g: &SimpleGrid, from, dir| g.first_where(&from, &dir, |c| *c != b'.');
+        assert_eq!(seat(&g, l(0, 0), RIGHT), Some(l(2, 0)));
+        assert_eq!(seat(&g, l(3, 0), DOWN_LEFT), Some(l(2, 1)));
+        assert_eq!(seat(&g, l(3, 0), DOWN), None);
+
+        // around a wrapping grid, each cell is met once
+        let h = g.clone().with_topology(Topology::WrapHorizontal);
+        assert_eq!(cells(&h, l(1, 0), RIGHT), b"b.a");
+        assert_eq!(cells(&h, l(1, 1), RIGHT.times(2)), b".");
+        assert_eq!(cells(&h, l(3, 0), DOWN_RIGHT), b"..");
+        assert_eq!(seat(&h, l(3, 1), LEFT), Some(l(2, 1)));
+        assert_eq!(seat(&h, l(2, 1), LEFT), None);
+        let t = g.with_topology(Topology::Torus);
+        assert_eq!(t.ray(&l(0, 0), &DOWN_RIGHT).count(), 11);
+        assert_eq!(cells(&t, l(0, 0), UP), b"d.");
+    }
+
+    #[test]
+    fn orientations() {
+        let g = SimpleGrid::create_from("abc\ndef").unwrap();
+        let t = |o| g.transform(o).to_string();
+        assert_eq!(t(Orientation::Identity), "abc\ndef\n");
+        assert_eq!(t(Orientation::RotateRight), "da\neb\nfc\n");
+        assert_eq!(t(Orientation::RotateHalf), "fed\ncba\n");
+        assert_eq!(t(Orientation::RotateLeft), "cf\nbe\nad\n");
+        assert_eq!(t(Orientation::FlipHorizontal), "cba\nfed\n");
+        assert_eq!(t(Orientation::FlipVertical), "def\nabc\n");
+        assert_eq!(t(Orientation::Transpose), "ad\nbe\ncf\n");
+        assert_eq!(t(Orientation::AntiTranspose), "fc\neb\nda\n");
+        assert_eq!(g.rotate_right().rotate_right().rotate_right(), g.rotate_left());
+        assert_eq!(g.flip_horizontal().flip_vertical(), g.transform(Orientation::RotateHalf));
+        assert_eq!(g.transpose().rotate_right(), g.flip_horizontal());
+
+        let view = g.view(Orientation::RotateRight);
+        assert_eq!((view.rows(), view.cols()), (3, 2));
+        assert_eq!((view.get(0, 0), view.get(2, 1), view.get(3, 0), view.get(0, 2)), (Some(&b'd'), Some(&b'c'), None, None));
+        assert_eq!(g.orientations().map(|v| v.to_grid().to_string()).collect::<HashSet<_>>().len(), 8);
+    }
+
+    #[test]
+    fn group() {
+        let g = SimpleGrid::create_from("abc\ndef").unwrap();
+        for a in Orientation::all() {
+            assert_eq!(a.then(a.inverse()), Orientation::Identity);
+            assert_eq!(g.transform(a).transform(a.inverse()), g);
+            for b in Orientation::all() {
+                assert_eq!(g.transform(a).transform(b), g.transform(a.then(b)), "{:?} then {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn patterns() {
+        let mut g = SimpleGrid::create_from("\
+#.##.
+.###.
+..#..
+##...").unwrap();
+        // an L with a wildcard corner, as is and in all orientations
+        let l = SimpleGrid::create_from("#?\n##").unwrap();
+        let at = |m: &[Match]| m.iter().map(|m| (m.row, m.col, m.orientation)).collect::<Vec<_>>();
+        assert_eq!(at(&g.find(&Pattern::new(&l, Some(b'?')))), vec![(0, 2, Orientation::Identity)]);
+        // the flips of an L are also rotations, so only 4 shapes are searched
+        let all = Pattern::in_all_orientations(&l, Some(b'?'));
+        let matches = g.find(&all);
+        assert_eq!(at(&matches), vec![
+            (0, 2, Orientation::Identity),
+            (0, 2, Orientation::RotateRight), (1, 2, Orientation::RotateRight),
+            (0, 2, Orientation::RotateHalf), (1, 1, Orientation::RotateHalf),
+            (0, 1, Orientation::RotateLeft), (0, 2, Orientation::RotateLeft),
+        ]);
+        assert!(matches.iter().all(|m| all.cells(m).all(|(pos, _)| g.get(pos.0, pos.1) == Some(&b'#'))));
+        let disjoint = g.find_disjoint(&all);
+        assert_eq!(disjoint, vec![matches[0]]);
+
+        // without a wildcard the whole shape must match, and a symmetric
+        // pattern is only searched once
+        let square = SimpleGrid::create_from("##\n##").unwrap();
+        assert_eq!(at(&g.find(&Pattern::in_all_orientations(&square, None))), vec![(0, 2, Orientation::Identity)]);
+        let bar = Pattern::in_all_orientations(&SimpleGrid::create_from("###").unwrap(), None);
+        assert_eq!(at(&g.find(&bar)), vec![(1, 1, Orientation::Identity), (0, 2, Orientation::RotateRight)]);
+        assert_eq!(at(&g.find_disjoint(&bar)), vec![(1, 1, Orientation::Identity)]);
+        assert!(g.find(&Pattern::new(&SimpleGrid::create_from("######").unwrap(), None)).is_empty());
+
+        for m in &disjoint {
+            g.stamp(&all, m, b'O');
+        }
+        g.overlay(&Pattern::new(&SimpleGrid::create_from("ab\nc.").unwrap(), Some(b'.')), &Match{ row: 2, col: 3, orientation: Orientation::Identity });
+        assert_eq!(g.to_string(), "#.O#.\n.#OO.\n..#ab\n##.c.\n");
+    }
+
+    #[test]
+    fn bytes() {
+        let g = SimpleGrid::create_from("ab\ncd").unwrap();
+        assert_eq!(g.get(1, 0), Some(&b'c'));
+        assert_eq!(g.to_string(), "ab\ncd\n");
+        assert_eq!(SimpleGrid::new(1, 2).to_string(), "  \n");
+    }
+}
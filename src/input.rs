@@ -0,0 +1,105 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+// The directory with the puzzle inputs, as DIR/NN.txt.
+pub const DEFAULT_DIR: &str = "input";
+
+// Where to read the puzzle inputs from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    // A directory with the inputs of all days, as DIR/NN.txt.
+    Dir(PathBuf),
+    // A single file, used whatever the day.
+    File(PathBuf),
+    // The standard input (which can only be read once).
+    Stdin,
+}
+
+impl Default for Source {
+    fn default() -> Source {
+        Source::Dir(PathBuf::from(DEFAULT_DIR))
+    }
+}
+
+impl Source {
+    // The source given by an --input argument: a file, or '-' for stdin.
+    pub fn from_arg(arg: &str) -> Source {
+        if arg == "-" { Source::Stdin } else { Source::File(PathBuf::from(arg)) }
+    }
+
+    // Whether the source can provide the inputs of different days.
+    pub fn is_dir(&self) -> bool {
+        matches!(self, Source::Dir(_))
+    }
+
+    pub fn path(&self, day: u32) -> Option<PathBuf> {
+        match self {
+            Source::Dir(dir) => Some(dir.join(format!("{:02}.txt", day))),
+            Source::File(path) => Some(path.clone()),
+            Source::Stdin => None,
+        }
+    }
+
+    pub fn read(&self, day: u32) -> std::io::Result<String> {
+        match self.path(day) {
+            Some(path) => read_file(&path),
+            None => {
+                let mut input = String::new();
+                std::io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            },
+        }
+    }
+}
+
+// Normalizes the text of an input, so that the parsers only have to deal
+// with one format: removes a UTF-8 BOM, converts CRLF line endings to LF,
+// removes trailing whitespace from each line and trailing blank lines, and
+// ends the last line with a newline.
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut text = String::with_capacity(input.len() + 1);
+    for line in input.lines() {
+        text.push_str(line.trim_end());
+        text.push('\n');
+    }
+    let len = text.trim_end_matches('\n').len();
+    text.truncate(if len == 0 { 0 } else { len + 1 });
+    text
+}
+
+// Reads a file, adding its path to the error message.
+fn read_file(path: &Path) -> std::io::Result<String> {
+    std::fs::read_to_string(path)
+        .map_err(|e| std::io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sources() {
+        assert_eq!(Source::default().path(7), Some(PathBuf::from("input/07.txt")));
+        assert_eq!(Source::Dir(PathBuf::from("alice")).path(23), Some(PathBuf::from("alice/23.txt")));
+        assert_eq!(Source::from_arg("my.txt").path(3), Some(PathBuf::from("my.txt")));
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+        assert!(Source::default().is_dir() && !Source::Stdin.is_dir());
+
+        let e = Source::Dir(PathBuf::from("no/such/dir")).read(1).unwrap_err();
+        assert!(e.to_string().starts_with("no/such/dir/01.txt: "));
+    }
+
+    #[test]
+    fn normalization() {
+        assert_eq!(normalize("a\nb\n"), "a\nb\n");
+        assert_eq!(normalize("a\r\n\r\nb\r\n"), "a\n\nb\n");
+        assert_eq!(normalize("\u{feff}a\nb\n"), "a\nb\n");
+        assert_eq!(normalize("a  \n\t\nb\t\n"), "a\n\nb\n");
+        assert_eq!(normalize("a\nb"), "a\nb\n");
+        assert_eq!(normalize("a\nb\n\n\n  \n"), "a\nb\n");
+        assert_eq!(normalize("  a\n b"), "  a\n b\n");
+        assert_eq!(normalize(""), "");
+        assert_eq!(normalize("\r\n\n"), "");
+    }
+}
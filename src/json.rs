@@ -0,0 +1,108 @@
+use std::fmt::{Display, Write};
+
+// A minimal JSON value, for machine-readable output.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Str(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    // Builds an object from (key, value) pairs, keeping their order.
+    pub fn object<'a>(fields: impl IntoIterator<Item = (&'a str, Value)>) -> Value {
+        Value::Object(fields.into_iter().map(|(k, v)| (k.to_string(), v)).collect())
+    }
+
+    pub fn str(s: &str) -> Value {
+        Value::Str(s.to_string())
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(v: Option<T>) -> Value {
+        v.map_or(Value::Null, Into::into)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Value {
+        Value::Str(s.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Value {
+        Value::Str(s)
+    }
+}
+
+fn write_str(f: &mut std::fmt::Formatter, s: &str) -> std::fmt::Result {
+    f.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Value::Null => f.write_str("null"),
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Int(n) => write!(f, "{}", n),
+            Value::Float(x) if x.is_finite() => write!(f, "{}", x),
+            Value::Float(_) => f.write_str("null"),
+            Value::Str(s) => write_str(f, s),
+            Value::Array(items) => {
+                f.write_char('[')?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 { f.write_char(',')?; }
+                    write!(f, "{}", item)?;
+                }
+                f.write_char(']')
+            },
+            Value::Object(fields) => {
+                f.write_char('{')?;
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    if i > 0 { f.write_char(',')?; }
+                    write_str(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                f.write_char('}')
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn output() {
+        let v = Value::object([
+            ("day", Value::Int(7)),
+            ("answers", Value::Array(vec![Value::str("a,b"), Value::Null])),
+            ("ok", Value::Bool(true)),
+            ("mean", Value::Float(1.5)),
+            ("nan", Value::Float(f64::NAN)),
+            ("error", Value::from(Some("line 1: \"x\"\n\u{1}"))),
+        ]);
+        assert_eq!(v.to_string(),
+            r#"{"day":7,"answers":["a,b",null],"ok":true,"mean":1.5,"nan":null,"error":"line 1: \"x\"\n\u0001"}"#);
+        assert_eq!(Value::from(None::<String>), Value::Null);
+    }
+}
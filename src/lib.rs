@@ -1 +1,20 @@
+pub mod answers;
+pub mod automaton;
+pub mod bench;
+pub mod days;
+pub mod error;
+pub mod fixtures;
+pub mod fuzz;
+pub mod generate;
 pub mod grid;
+pub mod input;
+pub mod json;
+pub mod math;
+pub mod memory;
+pub mod params;
+pub mod parser;
+pub mod pool;
+pub mod property;
+pub mod rng;
+pub mod solution;
+pub mod trace;
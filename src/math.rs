@@ -0,0 +1,151 @@
+// Number theory: greatest common divisors, modular arithmetic and the
+// Chinese remainder theorem. The modular functions take the modulus as an
+// argument, and multiply through u128 so that they do not overflow for
+// any modulus up to u64::MAX.
+
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+// The extended Euclidean algorithm: returns (g, x, y) such that g is the
+// (non-negative) gcd of a and b and a*x + b*y = g.
+pub fn egcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (g, x, y) = egcd_wide(a as i128, b as i128);
+    (g as i64, x as i64, y as i64)
+}
+
+fn egcd_wide(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < 0 { (-r0, -x0, -y0) } else { (r0, x0, y0) }
+}
+
+// a * b mod m, for m > 0.
+pub fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+// base^exp mod m, for m > 0, by exponentiation by squaring (0^0 is 1).
+pub fn pow_mod(base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    let mut base = base % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+// The inverse of a modulo m > 0, or None if a is not invertible, that is
+// if a and m are not coprime.
+pub fn mod_inv(a: u64, m: u64) -> Option<u64> {
+    let (g, x, _) = egcd_wide(a as i128, m as i128);
+    if g == 1 {
+        Some(x.rem_euclid(m as i128) as u64)
+    } else {
+        None
+    }
+}
+
+// Solves the system of congruences x = r (mod m) for all (r, m), with
+// m > 0, returning the solution as (x, lcm) with x in 0..lcm, where lcm
+// is the least common multiple of the moduli (all the solutions are x
+// plus multiples of lcm). The moduli do not need to be coprime. Returns
+// None if there is no solution, or if lcm does not fit in a u64.
+pub fn crt(congruences: &[(i64, u64)]) -> Option<(u64, u64)> {
+    let (mut x, mut lcm) = (0u64, 1u64);
+    for &(r, m) in congruences {
+        assert!(m > 0, "zero modulus");
+        let r = (r as i128).rem_euclid(m as i128) as u64;
+        // x + lcm*k = r (mod m), that is lcm*k = r - x (mod m), which
+        // has a solution iff the gcd g of lcm and m divides r - x.
+        let g = gcd(lcm, m);
+        let diff = (r as i128 - x as i128).rem_euclid(m as i128) as u64;
+        if !diff.is_multiple_of(g) {
+            return None;
+        }
+        let m_g = m / g;
+        let k = mul_mod(diff / g, mod_inv(lcm / g % m_g, m_g)?, m_g);
+        let next_lcm = (lcm / g).checked_mul(m)?;
+        x = ((x as u128 + lcm as u128 * k as u128) % next_lcm as u128) as u64;
+        lcm = next_lcm;
+    }
+    Some((x, lcm))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::property::{self, Config};
+
+    #[test]
+    fn euclid() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(gcd(0, 0), 0);
+        for (a, b) in [(240, 46), (-240, 46), (46, -240), (0, 5), (5, 0), (17, 17), (i64::MAX, i64::MAX - 1)] {
+            let (g, x, y) = egcd(a, b);
+            assert_eq!(g as u64, gcd(a.unsigned_abs(), b.unsigned_abs()));
+            assert_eq!(a as i128 * x as i128 + b as i128 * y as i128, g as i128, "{} {}", a, b);
+        }
+    }
+
+    #[test]
+    fn modular() {
+        assert_eq!(pow_mod(7, 0, 20201227), 1);
+        assert_eq!(pow_mod(0, 0, 1), 0);
+        assert_eq!(pow_mod(2, 10, 1000), 24);
+        assert_eq!(pow_mod(7, 8, 20201227), 5764801);
+        let m = u64::MAX - 58; // the largest 64-bit prime
+        assert_eq!(pow_mod(3, m - 1, m), 1);
+        assert_eq!(mul_mod(u64::MAX, u64::MAX, m), mul_mod(58, 58, m));
+
+        assert_eq!(mod_inv(3, 7), Some(5));
+        assert_eq!(mod_inv(1, 1), Some(0));
+        assert_eq!(mod_inv(4, 8), None);
+        assert_eq!(mod_inv(0, 5), None);
+        let inv = mod_inv(123456789, m).unwrap();
+        assert_eq!(mul_mod(inv, 123456789, m), 1);
+    }
+
+    #[test]
+    fn chinese_remainder() {
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(-1, 3), (-2, 5)]), Some((8, 15)));
+        // not coprime, but consistent or not
+        assert_eq!(crt(&[(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        // large moduli, and an lcm too large
+        let (p, q) = (4294967291, 4294967279);
+        assert_eq!(crt(&[(1, p), (0, q)]).map(|(x, _)| (x % p, x % q)), Some((1, 0)));
+        assert_eq!(crt(&[(1, p), (0, q), (0, 5)]), None);
+    }
+
+    #[test]
+    fn chinese_remainder_matches_naive() {
+        // small systems, compared with trying all the numbers up to the lcm
+        property::check(&Config::default().max_size(4),
+            |rng, size| (0..rng.below(size) + 1).map(|_| (rng.range(-20..=20), rng.range(1..=12))).collect::<Vec<_>>(),
+            |system| property::shrink_vec(system, |_| vec![]),
+            |system| {
+                let system: Vec<(i64, u64)> = system.iter().map(|&(r, m)| (r, m as u64)).collect();
+                let lcm = system.iter().fold(1, |l, &(_, m)| l / gcd(l, m) * m);
+                let naive = (0..lcm).find(|&x| system.iter().all(|&(r, m)| (x as i64 - r).rem_euclid(m as i64) == 0));
+                property::same(crt(&system), naive.map(|x| (x, lcm)))
+            });
+    }
+}
@@ -0,0 +1,151 @@
+// Counting of heap allocations, to see how much memory the solutions use.
+//
+// A binary opts in by installing the allocator:
+//
+//     #[global_allocator]
+//     static ALLOCATOR: CountingAllocator = CountingAllocator;
+//
+// and by calling enable(). The counters are kept per thread, so that days
+// and parts running in parallel are measured separately (memory freed by a
+// different thread than the one that allocated it is not tracked exactly).
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+pub struct CountingAllocator;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Clone, Copy, Default)]
+struct Counters {
+    allocations: u64,
+    bytes: u64,
+    current: i64,
+    peak: i64,
+}
+
+thread_local! {
+    // Const-initialized, so that using it does not allocate.
+    static COUNTERS: Cell<Counters> = const { Cell::new(Counters{ allocations: 0, bytes: 0, current: 0, peak: 0 }) };
+}
+
+fn record(allocated: usize, freed: usize) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    // The thread-local storage is not available while a thread is being
+    // torn down: those allocations are not counted.
+    let _ = COUNTERS.try_with(|c| {
+        let mut counters = c.get();
+        if allocated > 0 {
+            counters.allocations += 1;
+            counters.bytes += allocated as u64;
+        }
+        counters.current += allocated as i64 - freed as i64;
+        counters.peak = counters.peak.max(counters.current);
+        c.set(counters);
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+// Starts counting allocations (only effective if CountingAllocator is
+// the global allocator).
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+// The allocations made by a piece of code.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    // Number of allocations (including reallocations).
+    pub allocations: u64,
+    // Total bytes allocated.
+    pub bytes: u64,
+    // Peak of the heap in use, above the level at the start.
+    pub peak: u64,
+}
+
+// Runs f on the current thread, counting its allocations.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, AllocStats) {
+    let start = COUNTERS.with(|c| {
+        let mut counters = c.get();
+        counters.peak = counters.current;
+        c.set(counters);
+        counters
+    });
+    let result = f();
+    let end = COUNTERS.with(Cell::get);
+    let stats = AllocStats{
+        allocations: end.allocations - start.allocations,
+        bytes: end.bytes - start.bytes,
+        peak: (end.peak - start.current).max(0) as u64,
+    };
+    (result, stats)
+}
+
+// Formats a number of bytes with a readable unit.
+pub fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        0..=1023 => format!("{}B", bytes),
+        1024..=1048575 => format!("{:.1}KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.1}MiB", bytes as f64 / 1048576.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn units() {
+        assert_eq!(format_bytes(1000), "1000B");
+        assert_eq!(format_bytes(1536), "1.5KiB");
+        assert_eq!(format_bytes(30_000_001 * 4), "114.4MiB");
+    }
+
+    #[test]
+    fn counting() {
+        // The allocator is not installed in the tests: use it directly.
+        enable();
+        let layout = Layout::from_size_align(1000, 8).unwrap();
+        let ((), stats) = measure(|| unsafe {
+            let a = CountingAllocator.alloc(layout);
+            let b = CountingAllocator.alloc(layout);
+            CountingAllocator.dealloc(a, layout);
+            let b = CountingAllocator.realloc(b, layout, 3000);
+            CountingAllocator.dealloc(b, Layout::from_size_align(3000, 8).unwrap());
+        });
+        assert_eq!(stats, AllocStats{ allocations: 3, bytes: 5000, peak: 3000 });
+    }
+}
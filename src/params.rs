@@ -0,0 +1,86 @@
+use crate::error::ParseError;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+// A tunable parameter of a solution, such as the number of rounds to play.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: &'static str,
+    pub help: &'static str,
+}
+
+// The values of the parameters of a solution: the defaults, unless
+// overridden (e.g. from the command line).
+#[derive(Clone, Debug)]
+pub struct Params {
+    decls: &'static [Param],
+    values: HashMap<&'static str, String>,
+}
+
+impl Params {
+    pub fn new(decls: &'static [Param]) -> Params {
+        Params{ decls, values: HashMap::new() }
+    }
+
+    pub fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        let decl = self.decls.iter().find(|p| p.name == name)
+            .ok_or_else(|| format!("unknown parameter: {}", name))?;
+        self.values.insert(decl.name, value.to_string());
+        Ok(())
+    }
+
+    pub fn get_str(&self, name: &str) -> &str {
+        match self.values.get(name) {
+            Some(value) => value,
+            None => self.decls.iter().find(|p| p.name == name)
+                .unwrap_or_else(|| panic!("undeclared parameter: {}", name))
+                .default,
+        }
+    }
+
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<T, ParseError> {
+        let value = self.get_str(name);
+        value.parse().map_err(|_| ParseError::new(format!("invalid value for parameter '{}'", name), value))
+    }
+}
+
+// Splits an assignment such as "turns=2020" into name and value.
+pub fn parse_assignment(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((name, value)) if !name.is_empty() => Ok((name.to_string(), value.to_string())),
+        _ => Err(format!("expected NAME=VALUE: {}", s)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMS: &[Param] = &[
+        Param{ name: "turns", default: "2020", help: "" },
+        Param{ name: "slopes", default: "1:1,3:1", help: "" },
+    ];
+
+    #[test]
+    fn overrides() {
+        let mut params = Params::new(PARAMS);
+        assert_eq!(params.get::<usize>("turns"), Ok(2020));
+        assert_eq!(params.get_str("slopes"), "1:1,3:1");
+
+        params.set("turns", "50000000").unwrap();
+        assert_eq!(params.get::<usize>("turns"), Ok(50000000));
+        assert!(params.set("moves", "10").is_err());
+
+        params.set("turns", "many").unwrap();
+        assert_eq!(params.get::<usize>("turns").unwrap_err().to_string(), "invalid value for parameter 'turns' at 'many'");
+    }
+
+    #[test]
+    fn assignments() {
+        assert_eq!(parse_assignment("turns=10"), Ok(("turns".to_string(), "10".to_string())));
+        assert_eq!(parse_assignment("slopes=1:1,2:1"), Ok(("slopes".to_string(), "1:1,2:1".to_string())));
+        assert!(parse_assignment("turns").is_err());
+        assert!(parse_assignment("=10").is_err());
+    }
+}
@@ -0,0 +1,173 @@
+// A small toolkit for parsing the puzzle inputs.
+//
+// Everything works on slices of the input, so that the errors it reports
+// can be located in the input with ParseError::locate. A Scanner reads a
+// piece of text from left to right; the free functions split the input in
+// sections, lines and separated lists.
+
+use crate::error::{parse_number, ParseError};
+use std::str::FromStr;
+
+// The sections of the input, separated by blank lines (none for an empty
+// input). The sections do not end with a newline.
+pub fn sections(input: &str) -> impl Iterator<Item = &str> {
+    let input = input.trim_end_matches('\n');
+    (!input.is_empty()).then(|| input.split("\n\n")).into_iter().flatten()
+}
+
+// Parses each line of the text, collecting the results.
+pub fn lines<'a, T, C: FromIterator<T>>(
+    s: &'a str, f: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<C, ParseError> {
+    s.lines().map(f).collect()
+}
+
+// Parses each item of a list such as "1,2,3" (with sep ","), collecting
+// the results.
+pub fn list<'a, T, C: FromIterator<T>>(
+    s: &'a str, sep: &str, f: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<C, ParseError> {
+    s.split(sep).map(f).collect()
+}
+
+// Splits a pair such as "key:value" (with sep ":") at the first separator.
+pub fn key_value<'a>(s: &'a str, sep: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(sep)
+        .ok_or_else(|| ParseError::new(format!("expected '{}'", sep.escape_debug()), s))
+}
+
+// Reads a piece of text from left to right. The errors point to where
+// the scanner stands.
+#[derive(Clone, Copy, Debug)]
+pub struct Scanner<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(s: &'a str) -> Scanner<'a> {
+        Scanner{ rest: s }
+    }
+
+    // The text not read yet.
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    // An error at the current position.
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::new(message, self.rest)
+    }
+
+    // Reads the given text if it comes next.
+    pub fn try_literal(&mut self, lit: &str) -> bool {
+        match self.rest.strip_prefix(lit) {
+            Some(rest) => { self.rest = rest; true },
+            None => false,
+        }
+    }
+
+    // Reads the given text, which must come next.
+    pub fn literal(&mut self, lit: &str) -> Result<(), ParseError> {
+        if self.try_literal(lit) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected '{}'", lit.escape_debug())))
+        }
+    }
+
+    // Reads the characters matching a predicate (possibly none).
+    pub fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        let end = self.rest.find(|c| !f(c)).unwrap_or(self.rest.len());
+        let (taken, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        taken
+    }
+
+    // Reads everything that is left.
+    pub fn take_rest(&mut self) -> &'a str {
+        let rest = self.rest;
+        self.rest = &rest[rest.len()..];
+        rest
+    }
+
+    // Reads the text up to a delimiter, which is skipped.
+    pub fn until(&mut self, delim: &str) -> Result<&'a str, ParseError> {
+        let (taken, rest) = self.rest.split_once(delim)
+            .ok_or_else(|| ParseError::new(format!("expected '{}'", delim.escape_debug()), &self.rest[self.rest.len()..]))?;
+        self.rest = rest;
+        Ok(taken)
+    }
+
+    // Reads one character.
+    pub fn char(&mut self) -> Result<char, ParseError> {
+        let c = self.rest.chars().next().ok_or_else(|| self.error("unexpected end of text"))?;
+        self.rest = &self.rest[c.len_utf8()..];
+        Ok(c)
+    }
+
+    // Reads an integer, with an optional sign.
+    pub fn integer<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let sign = self.rest.strip_prefix(['+', '-']).map_or(0, |_| 1);
+        let len = sign + self.rest[sign..].find(|c: char| !c.is_ascii_digit()).unwrap_or(self.rest.len() - sign);
+        if len == sign {
+            return Err(self.error("expected a number"));
+        }
+        let (number, rest) = self.rest.split_at(len);
+        let n = parse_number(number)?;
+        self.rest = rest;
+        Ok(n)
+    }
+
+    // Checks that all the text has been read.
+    pub fn end(&self) -> Result<(), ParseError> {
+        if self.is_empty() { Ok(()) } else { Err(self.error("unexpected text")) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splitting() {
+        assert_eq!(sections("a\nb\n\nc\n").collect::<Vec<_>>(), ["a\nb", "c"]);
+        assert_eq!(sections("").count(), 0);
+        assert_eq!(lines::<_, Vec<i32>>("1\n-2\n", parse_number), Ok(vec![1, -2]));
+        assert_eq!(list::<_, Vec<u8>>("1, 2, 3", ", ", parse_number), Ok(vec![1, 2, 3]));
+        assert_eq!(list::<u8, Vec<_>>("1,x", ",", parse_number).unwrap_err().text, "x");
+        assert_eq!(key_value("ecl:gry", ":"), Ok(("ecl", "gry")));
+        assert_eq!(key_value("ecl", ":").unwrap_err().to_string(), "expected ':' at 'ecl'");
+    }
+
+    #[test]
+    fn scanning() {
+        let s = "mem[8] = -11 (x)";
+        let mut sc = Scanner::new(s);
+        assert!(!sc.try_literal("mask"));
+        sc.literal("mem[").unwrap();
+        assert_eq!(sc.integer::<u64>(), Ok(8));
+        assert_eq!(sc.until(" = "), Ok("]"));
+        assert_eq!(sc.integer::<i64>(), Ok(-11));
+        assert_eq!(sc.char(), Ok(' '));
+        assert_eq!(sc.take_while(|c| c != ')'), "(x");
+        assert_eq!(sc.end().unwrap_err().text, ")");
+        assert_eq!(sc.take_rest(), ")");
+        assert!(sc.end().is_ok());
+        assert_eq!(sc.char().unwrap_err().message, "unexpected end of text");
+
+        let e = Scanner::new("ab+").until("-").unwrap_err().locate(s);
+        assert_eq!((e.message.as_str(), e.line), ("expected '-'", None));
+        let e = Scanner::new(&s[4..]).until("\n").unwrap_err().locate(s);
+        assert_eq!((e.message.as_str(), e.column), ("expected '\\n'", Some(17)));
+
+        let mut sc = Scanner::new("+12x");
+        assert_eq!(sc.integer::<i32>(), Ok(12));
+        assert_eq!(sc.integer::<i32>().unwrap_err().to_string(), "expected a number at 'x'");
+        assert_eq!(Scanner::new("-").integer::<i32>().unwrap_err().text, "-");
+        assert_eq!(Scanner::new("300").integer::<u8>().unwrap_err().message, "invalid number");
+    }
+}
@@ -0,0 +1,131 @@
+use std::any::Any;
+use std::collections::VecDeque;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Condvar, Mutex, MutexGuard};
+
+// The queue of a pool of threads, through which tasks can schedule
+// further tasks.
+pub struct Queue<T> {
+    state: Mutex<State<T>>,
+    changed: Condvar,
+}
+
+struct State<T> {
+    tasks: VecDeque<T>,
+    running: usize,
+    panic: Option<Box<dyn Any + Send>>,
+}
+
+impl<T> Queue<T> {
+    fn lock(&self) -> MutexGuard<'_, State<T>> {
+        // Tasks never panic while holding the lock.
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    // Schedules a task, before the ones already waiting (so that the tasks
+    // spawned by a task are completed before starting new ones).
+    pub fn schedule(&self, task: T) {
+        self.lock().tasks.push_front(task);
+        self.changed.notify_one();
+    }
+
+    // Waits for a task, returning None when there is nothing left to do.
+    fn next(&self) -> Option<T> {
+        let mut state = self.lock();
+        loop {
+            if let Some(task) = state.tasks.pop_front() {
+                state.running += 1;
+                return Some(task);
+            }
+            if state.running == 0 {
+                return None;
+            }
+            state = self.changed.wait(state).unwrap_or_else(|e| e.into_inner());
+        }
+    }
+
+    fn done(&self, panic: Option<Box<dyn Any + Send>>) {
+        let mut state = self.lock();
+        state.running -= 1;
+        if state.panic.is_none() {
+            state.panic = panic;
+        }
+        // Wake up everybody, as this might be the end of the work.
+        self.changed.notify_all();
+    }
+}
+
+// Runs the tasks on 'jobs' threads (at least one), returning when all the
+// tasks, including the ones scheduled by other tasks, are done. If a task
+// panics, the others are still completed, then the panic is propagated.
+pub fn run<T: Send>(jobs: usize, tasks: impl IntoIterator<Item = T>, work: impl Fn(T, &Queue<T>) + Sync) {
+    let queue = Queue{
+        state: Mutex::new(State{ tasks: tasks.into_iter().collect(), running: 0, panic: None }),
+        changed: Condvar::new(),
+    };
+
+    std::thread::scope(|scope| {
+        for _ in 0..jobs.max(1) {
+            scope.spawn(|| {
+                while let Some(task) = queue.next() {
+                    let result = panic::catch_unwind(AssertUnwindSafe(|| work(task, &queue)));
+                    queue.done(result.err());
+                }
+            });
+        }
+    });
+
+    let panic = queue.lock().panic.take();
+    if let Some(payload) = panic {
+        panic::resume_unwind(payload);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn spawned_tasks() {
+        // Each task n > 0 spawns two tasks n-1: 2^(n+1)-1 tasks in total.
+        for jobs in [1, 4] {
+            let count = AtomicUsize::new(0);
+            run(jobs, [5, 3], |n: u32, queue| {
+                count.fetch_add(1, Ordering::Relaxed);
+                if n > 0 {
+                    queue.schedule(n - 1);
+                    queue.schedule(n - 1);
+                }
+            });
+            assert_eq!(count.into_inner(), 63 + 15);
+        }
+    }
+
+    #[test]
+    fn serial_order() {
+        let order = Mutex::new(Vec::new());
+        run(1, [1, 2, 3], |n: u32, queue| {
+            order.lock().unwrap().push(n);
+            if n < 10 {
+                queue.schedule(n * 10);
+            }
+        });
+        assert_eq!(order.into_inner().unwrap(), vec![1, 10, 2, 20, 3, 30]);
+    }
+
+    #[test]
+    fn panics() {
+        let count = AtomicUsize::new(0);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            run(2, 0..10, |n: u32, _| {
+                if n == 3 {
+                    panic!("task {}", n);
+                }
+                count.fetch_add(1, Ordering::Relaxed);
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(count.into_inner(), 9);
+    }
+}
@@ -0,0 +1,203 @@
+// A small property-based testing facility, in the spirit of QuickCheck:
+// a property is checked on many random cases of various sizes, and a
+// failing case is shrunk (made simpler while it still fails) before it is
+// reported, together with the seed to replay it.
+//
+// The number of cases and the seed can be changed with the environment
+// variables AOC_PROPERTY_CASES and AOC_PROPERTY_SEED.
+
+use crate::rng::Rng;
+use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub cases: usize,
+    // The cases cycle through the sizes 1 to max_size.
+    pub max_size: usize,
+    pub seed: u64,
+    // The maximum number of simpler cases tried while shrinking.
+    pub max_shrinks: usize,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        let env = |name: &str| std::env::var(name).ok().and_then(|v| v.parse().ok());
+        Config{
+            cases: env("AOC_PROPERTY_CASES").unwrap_or(1000) as usize,
+            max_size: 20,
+            seed: env("AOC_PROPERTY_SEED").unwrap_or(0),
+            max_shrinks: 10000,
+        }
+    }
+}
+
+impl Config {
+    pub fn cases(self, cases: usize) -> Config {
+        Config{ cases, ..self }
+    }
+
+    pub fn max_size(self, max_size: usize) -> Config {
+        Config{ max_size, ..self }
+    }
+}
+
+// Checks a property on random cases made by 'generate' (from an Rng and a
+// size), panicking with the simplest failing case found with 'shrink'
+// (which lists simpler variants of a case). The property fails by
+// returning an error or by panicking; cases that do not apply can simply
+// be accepted.
+pub fn check<T: Clone + Debug>(
+    config: &Config,
+    generate: impl Fn(&mut Rng, usize) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    property: impl Fn(&T) -> Result<(), String>,
+) {
+    let run = |case: &T| match panic::catch_unwind(AssertUnwindSafe(|| property(case))) {
+        Ok(result) => result,
+        Err(e) => Err(format!("panicked: {}", panic_message(e.as_ref()))),
+    };
+
+    for i in 0..config.cases {
+        let size = 1 + i % config.max_size.max(1);
+        let case = generate(&mut Rng::new(config.seed.wrapping_add(i as u64)), size);
+        let Err(error) = run(&case) else {
+            continue;
+        };
+
+        // Shrink greedily: move to the first simpler case that still fails.
+        let (mut case, mut error) = (case, error);
+        let (mut steps, mut tries) = (0, 0);
+        'shrinking: while tries < config.max_shrinks {
+            for simpler in shrink(&case) {
+                tries += 1;
+                if let Err(e) = run(&simpler) {
+                    (case, error) = (simpler, e);
+                    steps += 1;
+                    continue 'shrinking;
+                }
+                if tries >= config.max_shrinks {
+                    break;
+                }
+            }
+            break;
+        }
+        panic!("property failed on case {} (replay with AOC_PROPERTY_SEED={} AOC_PROPERTY_CASES={}), shrunk {} times: {}\n{:#?}",
+            i, config.seed, i + 1, steps, error, case);
+    }
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "?".to_string()
+    }
+}
+
+// Fails if the answers of a fast solver and of a naive one differ.
+pub fn same<A: PartialEq + Debug>(fast: A, naive: A) -> Result<(), String> {
+    if fast == naive {
+        Ok(())
+    } else {
+        Err(format!("the fast solver gives {:?}, the naive one {:?}", fast, naive))
+    }
+}
+
+// Simpler variants of an integer, towards the lower bound.
+pub fn shrink_int(n: i64, lo: i64) -> Vec<i64> {
+    let mut simpler = Vec::new();
+    if n > lo {
+        simpler.push(lo);
+        let mid = lo + (n - lo) / 2;
+        if mid > lo {
+            simpler.push(mid);
+        }
+        if n - 1 > mid {
+            simpler.push(n - 1);
+        }
+    }
+    simpler
+}
+
+// Simpler variants of a list: without chunks of elements (from halves to
+// single elements), then with one element made simpler.
+pub fn shrink_vec<T: Clone>(v: &[T], shrink_item: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    let mut simpler = Vec::new();
+    let mut chunk = v.len() / 2;
+    while chunk > 0 {
+        for start in (0..=v.len() - chunk).step_by(chunk) {
+            simpler.push([&v[..start], &v[start+chunk..]].concat());
+        }
+        chunk /= 2;
+    }
+    if v.len() == 1 {
+        simpler.push(Vec::new());
+    }
+    for (i, item) in v.iter().enumerate() {
+        for s in shrink_item(item) {
+            let mut w = v.to_vec();
+            w[i] = s;
+            simpler.push(w);
+        }
+    }
+    simpler
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shrinking() {
+        assert_eq!(shrink_int(10, 0), vec![0, 5, 9]);
+        assert_eq!(shrink_int(1, 0), vec![0]);
+        assert!(shrink_int(3, 3).is_empty());
+        let simpler = shrink_vec(&[1, 2, 3, 4], |&x| shrink_int(x, 0));
+        assert_eq!(simpler[..2], [vec![3, 4], vec![1, 2]]);
+        assert!(simpler.contains(&vec![1, 2, 4]));
+        assert!(simpler.contains(&vec![1, 0, 3, 4]));
+        assert_eq!(shrink_vec(&[7], |_| vec![]), vec![Vec::<i32>::new()]);
+    }
+
+    #[test]
+    fn passing() {
+        let config = Config::default().cases(200);
+        check(&config, |rng, size| (0..size).map(|_| rng.range(-50..=50)).collect::<Vec<_>>(),
+            |v| shrink_vec(v, |&x| shrink_int(x, -50)),
+            |v| same(v.iter().rev().sum::<i64>(), v.iter().sum::<i64>()));
+    }
+
+    #[test]
+    fn failing() {
+        // No list has a sum of 100 or more: shrunk to a single element of
+        // (close to) 100.
+        let config = Config::default().cases(500).max_size(30);
+        let result = panic::catch_unwind(|| {
+            check(&config, |rng, size| (0..size).map(|_| rng.range(0..=40)).collect::<Vec<_>>(),
+                |v| shrink_vec(v, |&x| shrink_int(x, 0)),
+                |v| if v.iter().sum::<i64>() < 100 { Ok(()) } else { Err("too large".to_string()) });
+        });
+        let message = panic_message(result.unwrap_err().as_ref());
+        assert!(message.contains("too large"), "{}", message);
+        let case = message.split_once("\n").unwrap().1;
+        let shrunk: Vec<i64> = case.trim_matches(|c: char| !c.is_ascii_digit())
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.parse().unwrap())
+            .collect();
+        assert!(shrunk.len() <= 4 && shrunk.iter().sum::<i64>() == 100, "{:?}", shrunk);
+
+        // Panics count as failures.
+        let result = panic::catch_unwind(|| {
+            check(&config, |rng, _| rng.range(0..=10), |&x| shrink_int(x, 0), |&x| {
+                assert!(x < 5, "x is {}", x);
+                Ok(())
+            });
+        });
+        let message = panic_message(result.unwrap_err().as_ref());
+        assert!(message.contains("panicked: x is 5"), "{}", message);
+    }
+}
@@ -0,0 +1,113 @@
+// A small seeded pseudo-random number generator (SplitMix64), so that
+// the generated inputs can be reproduced from their seed without
+// depending on external crates. It is fast and statistically good enough
+// for test data, but of course not suitable for cryptography.
+
+use std::ops::RangeInclusive;
+
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng{ state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    // A number in 0..n, which must not be empty. The bias of the
+    // multiplication is negligible for the sizes used here.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "empty range");
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    // A number in the range, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (lo, hi) = range.into_inner();
+        assert!(lo <= hi, "empty range");
+        let span = hi.abs_diff(lo) as u128 + 1;
+        lo.wrapping_add(((self.next_u64() as u128 * span) >> 64) as i64)
+    }
+
+    // True with probability p.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    // A random element of a slice, which must not be empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    // Shuffles a slice in place (Fisher-Yates).
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+
+    // k distinct numbers of 0..n, in random order.
+    pub fn sample(&mut self, n: usize, k: usize) -> Vec<usize> {
+        assert!(k <= n, "sample larger than the population");
+        let mut all: Vec<usize> = (0..n).collect();
+        for i in 0..k {
+            let j = i + self.below(n - i);
+            all.swap(i, j);
+        }
+        all.truncate(k);
+        all
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reproducible() {
+        // The reference values of SplitMix64 for seed 1234567.
+        let mut rng = Rng::new(1234567);
+        assert_eq!(rng.next_u64(), 6457827717110365317);
+        assert_eq!(rng.next_u64(), 3203168211198807973);
+        let a: Vec<_> = (0..10).map(|_| Rng::new(42).below(100)).collect();
+        assert!(a.iter().all(|&x| x == a[0]));
+    }
+
+    #[test]
+    fn ranges() {
+        let mut rng = Rng::new(7);
+        let mut seen = [false; 7];
+        for _ in 0..1000 {
+            let x = rng.range(-3..=3);
+            assert!((-3..=3).contains(&x));
+            seen[(x + 3) as usize] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+        assert_eq!(rng.range(5..=5), 5);
+        assert!((0..1000).map(|_| rng.range(i64::MIN..=i64::MAX)).any(|x| x < 0));
+        assert!((0..1000).all(|_| rng.below(3) < 3));
+        assert!(!rng.chance(0.0) && rng.chance(1.0));
+
+        let mut v: Vec<_> = (0..20).collect();
+        rng.shuffle(&mut v);
+        assert_ne!(v, (0..20).collect::<Vec<_>>());
+        v.sort();
+        assert_eq!(v, (0..20).collect::<Vec<_>>());
+
+        let mut s = rng.sample(10, 4);
+        assert_eq!(s.len(), 4);
+        s.sort();
+        s.dedup();
+        assert_eq!(s.len(), 4);
+        assert!(s.iter().all(|&x| x < 10));
+    }
+}
@@ -0,0 +1,132 @@
+use crate::answers::Answer;
+use crate::error::ParseError;
+use crate::input::{self, Source};
+use crate::params::{self, Param, Params};
+use crate::trace;
+use std::any::Any;
+
+// A puzzle solution, split into the parsing of the input and the two parts.
+pub trait Solution {
+    const DAY: u32;
+    const TITLE: &'static str;
+
+    // The tunable parameters, which are passed to parse_with().
+    const PARAMS: &'static [Param] = &[];
+
+    // The parts can return any type convertible to an Answer.
+    type Input;
+    type Answer1: Into<Answer>;
+    type Answer2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    // Solutions with parameters override this and implement parse() as
+    // parse_with(input, &Params::new(Self::PARAMS)).
+    fn parse_with(input: &str, _params: &Params) -> Result<Self::Input, ParseError> {
+        Self::parse(input)
+    }
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+
+    // Parses the input and solves both parts, panicking on invalid input.
+    fn solve(input: &str) -> (Answer, Answer) {
+        let input = &input::normalize(input);
+        let input = Self::parse(input).unwrap_or_else(|e| panic!("{}", e.locate(input)));
+        (Self::part1(&input).into(), Self::part2(&input).into())
+    }
+}
+
+// A parsed input, as returned by AnySolution. It can be shared between
+// threads, so that the two parts can run in parallel.
+pub type ParsedInput = Box<dyn Any + Send + Sync>;
+
+// Type-erased version of Solution, so that different days can be stored
+// in a single list and run programmatically. It is implemented for every
+// Solution; the parsed input is passed around as a ParsedInput.
+pub trait AnySolution: Sync {
+    fn day(&self) -> u32;
+    fn title(&self) -> &'static str;
+    fn params(&self) -> &'static [Param];
+    fn parse_input(&self, input: &str, params: &Params) -> Result<ParsedInput, ParseError>;
+    fn run_part1(&self, input: &(dyn Any + Send + Sync)) -> Answer;
+    fn run_part2(&self, input: &(dyn Any + Send + Sync)) -> Answer;
+}
+
+impl<S> AnySolution for S where S: Solution + Sync, S::Input: Send + Sync + 'static {
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn params(&self) -> &'static [Param] {
+        S::PARAMS
+    }
+
+    fn parse_input(&self, input: &str, params: &Params) -> Result<ParsedInput, ParseError> {
+        // Errors are located in the normalized input, which has the same lines.
+        let input = &input::normalize(input);
+        match trace::in_day(S::DAY, "parse", || S::parse_with(input, params)) {
+            Ok(parsed) => Ok(Box::new(parsed)),
+            Err(e) => Err(e.locate(input).in_day(S::DAY)),
+        }
+    }
+
+    fn run_part1(&self, input: &(dyn Any + Send + Sync)) -> Answer {
+        let input = input.downcast_ref().expect("input parsed by a different day");
+        trace::in_day(S::DAY, "part1", || S::part1(input).into())
+    }
+
+    fn run_part2(&self, input: &(dyn Any + Send + Sync)) -> Answer {
+        let input = input.downcast_ref().expect("input parsed by a different day");
+        trace::in_day(S::DAY, "part2", || S::part2(input).into())
+    }
+}
+
+// Reads the command line of the single-day binaries:
+// [--input PATH | --input - | --input-dir DIR] [--param NAME=VALUE]... [--trace SPEC]
+fn parse_args(s: &dyn AnySolution, args: &[String]) -> Result<(Source, Params), String> {
+    let mut source = Source::default();
+    let mut params = Params::new(s.params());
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let value = args.next().ok_or_else(|| format!("missing value for {}", arg))?;
+        match arg.as_str() {
+            "--input" => source = Source::from_arg(value),
+            "--input-dir" => source = Source::Dir(value.into()),
+            "--param" => {
+                let (name, value) = params::parse_assignment(value)?;
+                params.set(&name, &value)?;
+            },
+            "--trace" => trace::configure(value)?,
+            _ => return Err(format!("unknown option: {}", arg)),
+        }
+    }
+    Ok((source, params))
+}
+
+// Entry point for the single-day binaries.
+pub fn main(s: &dyn AnySolution) {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (source, params) = trace::configure_from_env().and_then(|_| parse_args(s, &args)).unwrap_or_else(|e| {
+        eprintln!("{}\nUsage: [--input PATH | --input - | --input-dir DIR] [--param NAME=VALUE]... [--trace SPEC]", e);
+        std::process::exit(2);
+    });
+    let input = source.read(s.day()).unwrap_or_else(|e| {
+        eprintln!("Cannot read input: {}", e);
+        std::process::exit(2);
+    });
+    let now = std::time::Instant::now();
+    let parsed = match s.parse_input(&input, &params) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("Invalid input: {}", e);
+            std::process::exit(1);
+        },
+    };
+    println!("Day {:02}: {}", s.day(), s.title());
+    println!("Part 1: {}", s.run_part1(parsed.as_ref()));
+    println!("Part 2: {}", s.run_part2(parsed.as_ref()));
+    println!("Time: {}ms", now.elapsed().as_millis());
+}
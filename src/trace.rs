@@ -0,0 +1,171 @@
+// Lightweight tracing of what the solutions are doing, printed on stderr.
+//
+// Events are emitted with the trace! macro, e.g. trace!(Debug, "turn {}", n),
+// and are printed only if their level is enabled for the day being run.
+// The levels are configured with a spec such as "debug" (all days) or
+// "info,8=trace,14-16=debug" (per day), given on the command line with
+// --trace or through the AOC_TRACE environment variable.
+
+use std::cell::Cell;
+use std::fmt::Arguments;
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off = 0,
+    Info = 1,
+    Debug = 2,
+    Trace = 3,
+}
+
+impl Level {
+    fn from_name(s: &str) -> Option<Level> {
+        match s {
+            "off" => Some(Level::Off),
+            "info" => Some(Level::Info),
+            "debug" => Some(Level::Debug),
+            "trace" => Some(Level::Trace),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Level::Off => "off",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+}
+
+pub const ENV_VAR: &str = "AOC_TRACE";
+
+// The enabled level for each day (index 0 is used outside of any day).
+// ANY allows a quick exit when tracing is off, which is the common case.
+static LEVELS: [AtomicU8; 26] = [const { AtomicU8::new(0) }; 26];
+static ANY: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    // The day and phase being run on this thread.
+    static CURRENT: Cell<(u32, &'static str)> = const { Cell::new((0, "")) };
+}
+
+// Parses a spec such as "info,8=trace,14-16=debug" into the level for each
+// day (index 0 being the default for code not running in any day).
+pub fn parse_spec(spec: &str) -> Result<[Level; 26], String> {
+    let mut levels = [Level::Off; 26];
+    for directive in spec.split(',').filter(|d| !d.is_empty()) {
+        let invalid = || format!("invalid trace directive: {}", directive);
+        match directive.split_once('=') {
+            None => {
+                let level = Level::from_name(directive).ok_or_else(invalid)?;
+                levels = [level; 26];
+            },
+            Some((days, level)) => {
+                let level = Level::from_name(level).ok_or_else(invalid)?;
+                let (first, last) = days.split_once('-').unwrap_or((days, days));
+                let first = first.parse::<usize>().map_err(|_| invalid())?;
+                let last = last.parse::<usize>().map_err(|_| invalid())?;
+                if first == 0 || first > last || last > 25 {
+                    return Err(invalid());
+                }
+                levels[first..=last].fill(level);
+            },
+        }
+    }
+    Ok(levels)
+}
+
+// Enables tracing according to a spec (see parse_spec).
+pub fn configure(spec: &str) -> Result<(), String> {
+    let levels = parse_spec(spec)?;
+    for (l, level) in LEVELS.iter().zip(levels) {
+        l.store(level as u8, Ordering::Relaxed);
+    }
+    ANY.store(levels.iter().any(|l| *l != Level::Off), Ordering::Relaxed);
+    Ok(())
+}
+
+// Enables tracing according to the AOC_TRACE environment variable, if set.
+pub fn configure_from_env() -> Result<(), String> {
+    match std::env::var(ENV_VAR) {
+        Ok(spec) => configure(&spec).map_err(|e| format!("{}: {}", ENV_VAR, e)),
+        Err(_) => Ok(()),
+    }
+}
+
+#[inline]
+pub fn enabled(level: Level) -> bool {
+    ANY.load(Ordering::Relaxed) && {
+        let day = CURRENT.with(|c| c.get().0) as usize;
+        level as u8 <= LEVELS[day.min(25)].load(Ordering::Relaxed)
+    }
+}
+
+pub fn emit(level: Level, args: Arguments) {
+    let (day, phase) = CURRENT.with(Cell::get);
+    let mut stderr = std::io::stderr().lock();
+    let _ = if day == 0 {
+        writeln!(stderr, "[{}] {}", level.name(), args)
+    } else {
+        writeln!(stderr, "[day {:02} {} {}] {}", day, phase, level.name(), args)
+    };
+}
+
+// Runs f as the given day and phase ("parse", "part1" or "part2"), which
+// select the trace level and are shown in the events.
+pub fn in_day<R>(day: u32, phase: &'static str, f: impl FnOnce() -> R) -> R {
+    let previous = CURRENT.with(|c| c.replace((day, phase)));
+    let result = f();
+    CURRENT.with(|c| c.set(previous));
+    result
+}
+
+// Emits a trace event if its level is enabled; the arguments are only
+// evaluated in that case.
+#[macro_export]
+macro_rules! trace {
+    ($level:ident, $($arg:tt)*) => {
+        if $crate::trace::enabled($crate::trace::Level::$level) {
+            $crate::trace::emit($crate::trace::Level::$level, format_args!($($arg)*));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn specs() {
+        let levels = parse_spec("debug").unwrap();
+        assert!(levels.iter().all(|l| *l == Level::Debug));
+
+        let levels = parse_spec("info,8=trace,14-16=debug").unwrap();
+        assert_eq!(levels[0], Level::Info);
+        assert_eq!(levels[8], Level::Trace);
+        assert_eq!(levels[13..=17], [Level::Info, Level::Debug, Level::Debug, Level::Debug, Level::Info]);
+
+        let levels = parse_spec("21=debug").unwrap();
+        assert_eq!((levels[0], levels[20], levels[21]), (Level::Off, Level::Off, Level::Debug));
+
+        assert!(parse_spec("verbose").is_err());
+        assert!(parse_spec("26=debug").is_err());
+        assert!(parse_spec("0=debug").is_err());
+        assert!(parse_spec("5-3=debug").is_err());
+    }
+
+    #[test]
+    fn current_day() {
+        let day = || CURRENT.with(Cell::get);
+        assert_eq!(day(), (0, ""));
+        in_day(8, "part1", || {
+            assert_eq!(day(), (8, "part1"));
+            in_day(9, "parse", || assert_eq!(day(), (9, "parse")));
+            assert_eq!(day(), (8, "part1"));
+        });
+        assert_eq!(day(), (0, ""));
+    }
+}